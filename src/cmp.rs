@@ -0,0 +1,145 @@
+//! Comparison & hashing traits
+
+use {
+  super::SteadyVec,
+  ::core::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+  },
+};
+
+/// Compare a `SteadyVec` to a slice, one segment at a time
+#[inline]
+fn eq_slice<A, B>(steady_vec: &SteadyVec<A>, other: &[B]) -> bool
+where
+  A: PartialEq<B>,
+{
+  if steady_vec.len != other.len() {
+    return false;
+  }
+
+  let mut other = other;
+  steady_vec.segments().all(|segment| {
+    let (head, rest) = other.split_at(segment.len());
+    other = rest;
+    segment == head
+  })
+}
+
+/// Compare a slice to a `SteadyVec`, one segment at a time
+#[inline]
+fn slice_eq<A, B>(slice: &[A], steady_vec: &SteadyVec<B>) -> bool
+where
+  A: PartialEq<B>,
+{
+  if slice.len() != steady_vec.len {
+    return false;
+  }
+
+  let mut slice = slice;
+  steady_vec.segments().all(|segment| {
+    let (head, rest) = slice.split_at(segment.len());
+    slice = rest;
+    head == segment
+  })
+}
+
+impl<A, B> PartialEq<SteadyVec<B>> for SteadyVec<A>
+where
+  A: PartialEq<B>,
+{
+  /// Since the layout of the subarrays only depends on the length, the
+  /// segments of both `SteadyVec`s line up and are compared as slices.
+  fn eq(&self, other: &SteadyVec<B>) -> bool {
+    self.len == other.len
+      && self
+        .segments()
+        .zip(other.segments())
+        .all(|(segment, other)| segment == other)
+  }
+}
+
+impl<E> Eq for SteadyVec<E> where E: Eq {}
+
+macro_rules! impl_eq_slice {
+  ([$($vars:tt)*] $rhs:ty, $lhs:ty) => {
+    impl<$($vars)* A, B> PartialEq<$rhs> for SteadyVec<A>
+    where
+      A: PartialEq<B>,
+    {
+      #[inline]
+      fn eq(&self, other: &$rhs) -> bool {
+        eq_slice(self, &other[..])
+      }
+    }
+
+    impl<$($vars)* A, B> PartialEq<SteadyVec<B>> for $lhs
+    where
+      A: PartialEq<B>,
+    {
+      #[inline]
+      fn eq(&self, other: &SteadyVec<B>) -> bool {
+        slice_eq(&self[..], other)
+      }
+    }
+  };
+}
+
+impl_eq_slice!([] Vec<B>, Vec<A>);
+impl_eq_slice!([][B], [A]);
+impl_eq_slice!(['b,] &'b [B], &'b [A]);
+impl_eq_slice!(['b,] &'b mut [B], &'b mut [A]);
+impl_eq_slice!([const N: usize,] [B; N], [A; N]);
+
+impl<E> PartialOrd for SteadyVec<E>
+where
+  E: PartialOrd,
+{
+  /// Compares the elements lexicographically, like `[E]`
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    // The segments line up, so only the final pair of segments may differ in
+    // length; comparing them as slices gives the correct lexicographic result.
+    for (segment, other) in self.segments().zip(other.segments()) {
+      match segment.partial_cmp(other) {
+        Some(Ordering::Equal) => (),
+        non_eq => return non_eq,
+      }
+    }
+    self.len.partial_cmp(&other.len)
+  }
+}
+
+impl<E> Ord for SteadyVec<E>
+where
+  E: Ord,
+{
+  /// Compares the elements lexicographically, like `[E]`
+  fn cmp(&self, other: &Self) -> Ordering {
+    for (segment, other) in self.segments().zip(other.segments()) {
+      match segment.cmp(other) {
+        Ordering::Equal => (),
+        non_eq => return non_eq,
+      }
+    }
+    self.len.cmp(&other.len)
+  }
+}
+
+impl<E> Hash for SteadyVec<E>
+where
+  E: Hash,
+{
+  /// Feeds the same data to the `Hasher` as the equivalent `[E]` would: the
+  /// length, followed by the elements
+  ///
+  /// Each segment is hashed with [`Hash::hash_slice`], so the hash is equal to
+  /// the hash of the equivalent `[E]` when the `Hasher` does not depend on
+  /// how its input is split between `write` calls, as is the case for the
+  /// standard library's `DefaultHasher`.
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    state.write_usize(self.len);
+    for segment in self.segments() {
+      E::hash_slice(segment, state);
+    }
+  }
+}
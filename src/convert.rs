@@ -0,0 +1,144 @@
+//! Conversions to & from other collections
+
+use {
+  super::SteadyVec,
+  ::core::{mem::ManuallyDrop, ptr},
+};
+
+impl<E> SteadyVec<E> {
+  /// Moves `count` elements from the contiguous memory at `src` onto the end
+  ///
+  /// The elements are copied in bulk, one subarray run at a time.
+  ///
+  /// # Safety
+  ///
+  /// - `src` must be valid for reads of `count` elements, and must not overlap
+  ///   the `SteadyVec`.
+  /// - the elements are moved, so the caller must not use or drop them
+  ///   afterwards. If this panics, the elements are leaked.
+  pub(crate) unsafe fn append_from_ptr(
+    &mut self,
+    src: *const E,
+    count: usize,
+  ) {
+    self.reserve(count);
+    // safety: `reserve` ensures the subarrays exist, and the caller promises
+    // the source is valid.
+    unsafe { self.copy_from_ptr_unchecked(src, self.len, count) };
+    self.len += count;
+  }
+
+  /// Moves all the elements of a `Vec` onto the end, then frees the `Vec`'s
+  /// allocation
+  fn append_vec(&mut self, mut vec: Vec<E>) {
+    let count = vec.len();
+    // safety:
+    // - the length of the `Vec` is set to 0 first, so the elements are not
+    //   dropped along with it.
+    // - the `Vec`'s buffer is valid for reads of `count` elements, and is not
+    //   freed until `vec` is dropped at the end of this function.
+    unsafe {
+      vec.set_len(0);
+      self.append_from_ptr(vec.as_ptr(), count);
+    }
+  }
+
+  /// Moves all the elements into a `Vec` with exactly enough capacity
+  ///
+  /// The elements are copied in bulk, one segment at a time.
+  pub fn into_vec(mut self) -> Vec<E> {
+    let mut vec: Vec<E> = Vec::with_capacity(self.len);
+    let mut copied = 0;
+    for segment in self.segments() {
+      // safety: `vec` has capacity for `len` elements, and the segments
+      // contain `len` elements in total.
+      unsafe {
+        ptr::copy_nonoverlapping(
+          segment.as_ptr(),
+          vec.as_mut_ptr().add(copied),
+          segment.len(),
+        );
+      }
+      copied += segment.len();
+    }
+
+    // safety:
+    // - the elements have been moved into `vec`, so setting the length of
+    //   `self` to 0 prevents them being dropped again.
+    // - the first `copied` elements of `vec` are initialised.
+    self.len = 0;
+    unsafe { vec.set_len(copied) };
+    vec
+  }
+
+  /// Clones all the elements into a `Vec` with exactly enough capacity
+  ///
+  /// The elements are cloned one segment at a time.
+  pub fn to_vec(&self) -> Vec<E>
+  where
+    E: Clone,
+  {
+    let mut vec = Vec::with_capacity(self.len);
+    for segment in self.segments() {
+      vec.extend_from_slice(segment);
+    }
+    vec
+  }
+}
+
+impl<E> From<SteadyVec<E>> for Vec<E> {
+  fn from(steady_vec: SteadyVec<E>) -> Self {
+    steady_vec.into_vec()
+  }
+}
+
+impl<E> From<Box<SteadyVec<E>>> for Vec<E> {
+  fn from(steady_vec: Box<SteadyVec<E>>) -> Self {
+    steady_vec.into_vec()
+  }
+}
+
+macro_rules! impl_steady_vec_from {
+  ($steady_vec_variant:ty, $new:expr) => {
+    impl<E> From<Vec<E>> for $steady_vec_variant {
+      /// Moves the elements into the `SteadyVec` in bulk, then frees the
+      /// `Vec`'s allocation without dropping the elements
+      fn from(vec: Vec<E>) -> Self {
+        let mut steady_vec = $new;
+        steady_vec.append_vec(vec);
+        steady_vec
+      }
+    }
+
+    impl<E> From<Box<[E]>> for $steady_vec_variant {
+      fn from(boxed_slice: Box<[E]>) -> Self {
+        Self::from(Vec::from(boxed_slice))
+      }
+    }
+
+    impl<E, const N: usize> From<[E; N]> for $steady_vec_variant {
+      fn from(array: [E; N]) -> Self {
+        let array = ManuallyDrop::new(array);
+        let mut steady_vec = $new;
+        // safety: the elements are moved out of the array, which is never
+        // dropped.
+        unsafe { steady_vec.append_from_ptr(array.as_ptr(), N) };
+        steady_vec
+      }
+    }
+
+    impl<E> From<&[E]> for $steady_vec_variant
+    where
+      E: Clone,
+    {
+      fn from(slice: &[E]) -> Self {
+        let mut steady_vec = $new;
+        steady_vec.extend_from_slice(slice);
+        steady_vec
+      }
+    }
+  };
+}
+
+impl_steady_vec_from!(SteadyVec<E>, SteadyVec::new());
+impl_steady_vec_from!(Box<SteadyVec<E>>, SteadyVec::new_boxed());
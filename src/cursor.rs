@@ -0,0 +1,297 @@
+//! Cursors over a `SteadyVec`
+
+use {
+  super::{raw_cursor::RawCursor, SteadyVec},
+  ::core::mem,
+};
+
+/// A cursor over the elements of a `SteadyVec`
+///
+/// The cursor points at an index from `0..=len`; when it points at `len`
+/// there is no current element. The pointer to the current element & the
+/// bounds of its subarray are cached, so moving to an adjacent element is
+/// O(1), and only recomputes the subarray when crossing into a different
+/// one. Created using [`SteadyVec::cursor`].
+pub struct Cursor<'s, E: 's> {
+  steady_vec: &'s SteadyVec<E>,
+  raw: RawCursor<E>,
+}
+
+// safety: `Cursor` behaves like a `&SteadyVec<E>`; the `RawCursor` only
+// caches a pointer into it.
+unsafe impl<'s, E: Sync> Send for Cursor<'s, E> {}
+unsafe impl<'s, E: Sync> Sync for Cursor<'s, E> {}
+
+impl<'s, E> Cursor<'s, E> {
+  /// `index` must be less than or equal to `steady_vec.len`.
+  pub(crate) fn new(steady_vec: &'s SteadyVec<E>, index: usize) -> Self {
+    let mut cursor = Cursor {
+      steady_vec,
+      raw: RawCursor::new(index),
+    };
+    cursor.refresh();
+    cursor
+  }
+
+  /// Update the cached pointer after the cursor moves
+  #[inline]
+  fn refresh(&mut self) {
+    if self.raw.index() < self.steady_vec.len {
+      // safety: the index is in bounds, so the subarray exists.
+      unsafe { self.raw.ptr(self.steady_vec) };
+    }
+  }
+
+  /// The index the cursor points to
+  ///
+  /// This is equal to the length if there is no current element.
+  #[inline]
+  pub fn index(&self) -> usize {
+    self.raw.index()
+  }
+
+  /// Get the element the cursor points to, or `None` if the cursor points to
+  /// the end
+  #[inline]
+  pub fn current(&self) -> Option<&'s E> {
+    if self.raw.index() >= self.steady_vec.len {
+      return None;
+    }
+
+    // safety: the index is in bounds, and the pointer was refreshed when the
+    // cursor last moved.
+    Some(unsafe { &*self.raw.cached_ptr() })
+  }
+
+  /// Move the cursor to the next element
+  ///
+  /// If the cursor already points to the end, this does nothing.
+  #[inline]
+  pub fn move_next(&mut self) {
+    if self.raw.index() < self.steady_vec.len {
+      self.raw.advance();
+      self.refresh();
+    }
+  }
+
+  /// Move the cursor to the previous element
+  ///
+  /// If the cursor already points to the first element, this does nothing.
+  #[inline]
+  pub fn move_prev(&mut self) {
+    if self.raw.index() > 0 {
+      self.raw.retreat();
+      self.refresh();
+    }
+  }
+
+  /// Move the cursor to `index`
+  ///
+  /// # Panics
+  ///
+  /// Panics if `index` is greater than the length.
+  pub fn seek(&mut self, index: usize) {
+    if index > self.steady_vec.len {
+      panic!(
+        "index is out of bounds, index: {index}, len: {len}",
+        len = self.steady_vec.len
+      );
+    }
+    self.raw.seek(index);
+    self.refresh();
+  }
+
+  /// Get the `SteadyVec` the cursor is over
+  #[inline]
+  pub fn as_steady_vec(&self) -> &'s SteadyVec<E> {
+    self.steady_vec
+  }
+}
+
+impl<'s, E> Clone for Cursor<'s, E> {
+  fn clone(&self) -> Self {
+    Cursor::new(self.steady_vec, self.raw.index())
+  }
+}
+
+/// A cursor over the elements of a `SteadyVec` with editing operations
+///
+/// Like [`Cursor`], but also allows modifying the current element, and
+/// inserting or removing elements at the cursor. Created using
+/// [`SteadyVec::cursor_mut`].
+///
+/// Moving the cursor is O(1), but inserting or removing elements shifts every
+/// following element, so is O(n) like the equivalent `SteadyVec` methods.
+//
+// # Safety
+//
+// The cached pointer remains valid when elements are inserted or removed,
+// since the subarray allocations never move. It must be refreshed whenever
+// the index changes, or the `SteadyVec` is replaced.
+pub struct CursorMut<'s, E: 's> {
+  steady_vec: &'s mut SteadyVec<E>,
+  raw: RawCursor<E>,
+}
+
+// safety: `CursorMut` behaves like a `&mut SteadyVec<E>`; the `RawCursor`
+// only caches a pointer into it.
+unsafe impl<'s, E: Send> Send for CursorMut<'s, E> {}
+unsafe impl<'s, E: Sync> Sync for CursorMut<'s, E> {}
+
+impl<'s, E> CursorMut<'s, E> {
+  /// `index` must be less than or equal to `steady_vec.len`.
+  pub(crate) fn new(steady_vec: &'s mut SteadyVec<E>, index: usize) -> Self {
+    let mut cursor = CursorMut {
+      steady_vec,
+      raw: RawCursor::new(index),
+    };
+    cursor.refresh();
+    cursor
+  }
+
+  /// Update the cached pointer after the cursor moves
+  #[inline]
+  fn refresh(&mut self) {
+    if self.raw.index() < self.steady_vec.len {
+      // safety: the index is in bounds, so the subarray exists.
+      unsafe { self.raw.ptr(self.steady_vec) };
+    }
+  }
+
+  /// The index the cursor points to
+  ///
+  /// This is equal to the length if there is no current element.
+  #[inline]
+  pub fn index(&self) -> usize {
+    self.raw.index()
+  }
+
+  /// Get the element the cursor points to, or `None` if the cursor points to
+  /// the end
+  #[inline]
+  pub fn current(&self) -> Option<&E> {
+    if self.raw.index() >= self.steady_vec.len {
+      return None;
+    }
+
+    // safety: the index is in bounds, and the pointer was refreshed when the
+    // cursor last moved.
+    Some(unsafe { &*self.raw.cached_ptr() })
+  }
+
+  /// Mutably get the element the cursor points to, or `None` if the cursor
+  /// points to the end
+  #[inline]
+  pub fn current_mut(&mut self) -> Option<&mut E> {
+    if self.raw.index() >= self.steady_vec.len {
+      return None;
+    }
+
+    // safety: as above, and the `SteadyVec` is mutably borrowed through
+    // `self`.
+    Some(unsafe { &mut *self.raw.cached_ptr() })
+  }
+
+  /// Move the cursor to the next element
+  ///
+  /// If the cursor already points to the end, this does nothing.
+  #[inline]
+  pub fn move_next(&mut self) {
+    if self.raw.index() < self.steady_vec.len {
+      self.raw.advance();
+      self.refresh();
+    }
+  }
+
+  /// Move the cursor to the previous element
+  ///
+  /// If the cursor already points to the first element, this does nothing.
+  #[inline]
+  pub fn move_prev(&mut self) {
+    if self.raw.index() > 0 {
+      self.raw.retreat();
+      self.refresh();
+    }
+  }
+
+  /// Move the cursor to `index`
+  ///
+  /// # Panics
+  ///
+  /// Panics if `index` is greater than the length.
+  pub fn seek(&mut self, index: usize) {
+    if index > self.steady_vec.len {
+      panic!(
+        "index is out of bounds, index: {index}, len: {len}",
+        len = self.steady_vec.len
+      );
+    }
+    self.raw.seek(index);
+    self.refresh();
+  }
+
+  /// Insert an element before the current element, shifting the current &
+  /// all following elements to the right
+  ///
+  /// The cursor keeps pointing to the same element. If the cursor points to
+  /// the end, the element is pushed onto the end.
+  ///
+  /// O(n) time complexity.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the new capacity would exceed [`SteadyVec::MAX_CAPACITY`].
+  pub fn insert_before(&mut self, value: E) {
+    self.steady_vec.insert(self.raw.index(), value);
+    self.raw.advance();
+    self.refresh();
+  }
+
+  /// Remove and return the current element, shifting all following elements
+  /// to the left
+  ///
+  /// The cursor then points to the element that followed the removed one.
+  /// Returns `None` if the cursor points to the end.
+  ///
+  /// O(n) time complexity.
+  pub fn remove_current(&mut self) -> Option<E> {
+    if self.raw.index() >= self.steady_vec.len {
+      return None;
+    }
+
+    let element = self.steady_vec.remove(self.raw.index());
+    self.refresh();
+    Some(element)
+  }
+
+  /// Split the `SteadyVec` before the current element
+  ///
+  /// Returns a `SteadyVec` containing the elements before the cursor, while
+  /// the current & following elements are left in place. The cursor then
+  /// points to index 0.
+  ///
+  /// O(n) time complexity.
+  pub fn split_before(&mut self) -> SteadyVec<E> {
+    let tail = self.steady_vec.split_off(self.raw.index());
+    let front = mem::replace(self.steady_vec, tail);
+
+    // the elements now belong to different allocations, so the cached
+    // pointer must be recomputed
+    self.raw = RawCursor::new(0);
+    self.refresh();
+    front
+  }
+
+  /// Get a read-only cursor pointing to the current element
+  ///
+  /// The `CursorMut` is borrowed for the lifetime of the `Cursor`.
+  pub fn as_cursor(&self) -> Cursor<E> {
+    Cursor::new(self.steady_vec, self.raw.index())
+  }
+
+  /// Get the `SteadyVec` the cursor is over
+  #[inline]
+  pub fn as_steady_vec(&self) -> &SteadyVec<E> {
+    self.steady_vec
+  }
+}
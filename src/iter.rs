@@ -1,9 +1,13 @@
 //! Iterator implementations
 
 use {
-  super::{index_metadata, subarray_capacity, SteadyVec},
+  super::{
+    index_metadata, initialised_subarray_count, raw_cursor::RawCursor,
+    subarray_capacity, Segment, SteadyVec,
+  },
   ::core::{
-    cmp::Ordering, iter::FusedIterator, marker::PhantomData, mem::ManuallyDrop,
+    cmp::Ordering, iter::FusedIterator, marker::PhantomData,
+    mem::ManuallyDrop, ops::Range, ptr, slice,
   },
 };
 
@@ -22,9 +26,19 @@ pub struct SteadyVecIter<'s, E: 's> {
 
 impl<'s, E> SteadyVecIter<'s, E> {
   pub(crate) fn new(steady_vec: &'s SteadyVec<E>) -> Self {
+    Self::with_range(steady_vec, 0..steady_vec.len)
+  }
+
+  /// An iterator over the elements in `range` only
+  ///
+  /// `range` must be in bounds of `steady_vec.len`.
+  pub(crate) fn with_range(
+    steady_vec: &'s SteadyVec<E>,
+    range: Range<usize>,
+  ) -> Self {
     SteadyVecIter {
-      index: 0,
-      len: steady_vec.len,
+      index: range.start,
+      len: range.end,
       steady_vec,
     }
   }
@@ -34,6 +48,10 @@ impl<'s, E> Iterator for SteadyVecIter<'s, E> {
   type Item = &'s E;
 
   fn next(&mut self) -> Option<Self::Item> {
+    if self.index >= self.len {
+      return None;
+    }
+
     let element = self.steady_vec.get(self.index);
     if element.is_some() {
       self.index += 1;
@@ -45,6 +63,56 @@ impl<'s, E> Iterator for SteadyVecIter<'s, E> {
     let remaining = self.len - self.index;
     (remaining, Some(remaining))
   }
+
+  // The following are overridden to walk each contiguous run as a slice,
+  // rather than looking up the subarray of every element.
+
+  fn fold<B, F>(self, init: B, mut f: F) -> B
+  where
+    F: FnMut(B, Self::Item) -> B,
+  {
+    // safety: `index..len` is in bounds of the `SteadyVec`.
+    let runs = unsafe { self.steady_vec.run_slices(self.index..self.len) };
+    runs.fold(init, |acc, run| run.iter().fold(acc, &mut f))
+  }
+
+  fn position<P>(&mut self, mut predicate: P) -> Option<usize>
+  where
+    P: FnMut(Self::Item) -> bool,
+  {
+    let steady_vec = self.steady_vec;
+    let start = self.index;
+    // safety: `index..len` is in bounds of the `SteadyVec`.
+    for run in unsafe { steady_vec.run_slices(self.index..self.len) } {
+      match run.iter().position(&mut predicate) {
+        Some(position) => {
+          self.index += position + 1;
+          return Some(self.index - 1 - start);
+        },
+        None => self.index += run.len(),
+      }
+    }
+    None
+  }
+
+  fn rposition<P>(&mut self, mut predicate: P) -> Option<usize>
+  where
+    P: FnMut(Self::Item) -> bool,
+  {
+    let steady_vec = self.steady_vec;
+    // safety: `index..len` is in bounds of the `SteadyVec`.
+    for run in unsafe { steady_vec.run_slices(self.index..self.len) }.rev() {
+      let run_start = self.len - run.len();
+      match run.iter().rposition(&mut predicate) {
+        Some(position) => {
+          self.len = run_start + position;
+          return Some(self.len - self.index);
+        },
+        None => self.len = run_start,
+      }
+    }
+    None
+  }
 }
 
 impl<'s, E> FusedIterator for SteadyVecIter<'s, E> {}
@@ -58,12 +126,25 @@ impl<'s, E> ExactSizeIterator for SteadyVecIter<'s, E> {
 
 impl<'s, E> DoubleEndedIterator for SteadyVecIter<'s, E> {
   fn next_back(&mut self) -> Option<Self::Item> {
+    if self.index >= self.len {
+      return None;
+    }
+
     let element = self.steady_vec.get(self.len - 1);
     if element.is_some() {
       self.len -= 1;
     }
     element
   }
+
+  fn rfold<B, F>(self, init: B, mut f: F) -> B
+  where
+    F: FnMut(B, Self::Item) -> B,
+  {
+    // safety: `index..len` is in bounds of the `SteadyVec`.
+    let runs = unsafe { self.steady_vec.run_slices(self.index..self.len) };
+    runs.rfold(init, |acc, run| run.iter().rfold(acc, &mut f))
+  }
 }
 
 /// A mutably borrowing Iterator
@@ -73,18 +154,43 @@ impl<'s, E> DoubleEndedIterator for SteadyVecIter<'s, E> {
 ///
 /// This iterator also implements [`FusedIterator`], [`ExactSizeIterator`], &
 /// [`DoubleEndedIterator`].
+//
+// # Safety
+//
+// Holds a shared reference for the same reason as `SteadySliceMut`; each index
+// is yielded at most once.
 pub struct SteadyVecIterMut<'s, E: 's> {
-  steady_vec: *mut SteadyVec<E>,
+  steady_vec: &'s SteadyVec<E>,
   index: usize,
   len: usize,
-  _lifetime: PhantomData<&'s mut SteadyVec<E>>,
+  _lifetime: PhantomData<&'s mut E>,
 }
 
+// safety: `SteadyVecIterMut` behaves like a `&mut [E]`, even though it only
+// holds a shared reference to the `SteadyVec`.
+unsafe impl<'s, E: Send> Send for SteadyVecIterMut<'s, E> {}
+unsafe impl<'s, E: Sync> Sync for SteadyVecIterMut<'s, E> {}
+
 impl<'s, E: 's> SteadyVecIterMut<'s, E> {
-  pub(crate) fn new(steady_vec: &mut SteadyVec<E>) -> Self {
+  pub(crate) fn new(steady_vec: &'s mut SteadyVec<E>) -> Self {
+    let range = 0..steady_vec.len;
+    // safety: `range` covers the whole vec, which we borrow mutably.
+    unsafe { Self::with_range(steady_vec, range) }
+  }
+
+  /// An iterator over the elements in `range` only
+  ///
+  /// # Safety
+  ///
+  /// - `range` must be in bounds of `steady_vec.len`.
+  /// - no other references to the elements in `range` may exist for `'s`.
+  pub(crate) unsafe fn with_range(
+    steady_vec: &'s SteadyVec<E>,
+    range: Range<usize>,
+  ) -> Self {
     SteadyVecIterMut {
-      index: 0,
-      len: steady_vec.len,
+      index: range.start,
+      len: range.end,
       steady_vec,
       _lifetime: PhantomData,
     }
@@ -95,18 +201,16 @@ impl<'s, E> Iterator for SteadyVecIterMut<'s, E> {
   type Item = &'s mut E;
 
   fn next(&mut self) -> Option<Self::Item> {
-    // safety:
-    // - the lifetime of the pointer to steady_vec is known to be alive since
-    //   the iterator also explicitly stores it.
-    // - the ptr was known to be non-null when the iterator was constructed.
-    let steady_vec: &'s mut SteadyVec<E> =
-      unsafe { self.steady_vec.as_mut().unwrap_unchecked() };
-
-    let element = steady_vec.get_mut(self.index);
-    if element.is_some() {
-      self.index += 1;
+    if self.index >= self.len {
+      return None;
     }
-    element
+
+    // safety:
+    // - `self.index` is in bounds, so the element is initialised.
+    // - each index is yielded at most once (see the note above).
+    let element = unsafe { &mut *self.steady_vec.element_ptr(self.index) };
+    self.index += 1;
+    Some(element)
   }
 
   fn size_hint(&self) -> (usize, Option<usize>) {
@@ -126,19 +230,16 @@ impl<'s, E> FusedIterator for SteadyVecIterMut<'s, E> {}
 
 impl<'s, E> DoubleEndedIterator for SteadyVecIterMut<'s, E> {
   fn next_back(&mut self) -> Option<Self::Item> {
-    // safety:
-    // - the lifetime of the pointer to steady_vec is known to be alive since
-    //   the iterator also explicitly captures the lifetime of an exclusive
-    //   reference to the underlying SteadyVec.
-    // - the ptr was known to be non-null when the iterator was constructed.
-    let steady_vec: &'s mut SteadyVec<E> =
-      unsafe { self.steady_vec.as_mut().unwrap_unchecked() };
-
-    let element = steady_vec.get_mut(self.len - 1);
-    if element.is_some() {
-      self.len -= 1;
+    if self.index >= self.len {
+      return None;
     }
-    element
+
+    self.len -= 1;
+    // safety:
+    // - `self.len` was in bounds, so the element is initialised.
+    // - each index is yielded at most once (see the note above).
+    let element = unsafe { &mut *self.steady_vec.element_ptr(self.len) };
+    Some(element)
   }
 }
 
@@ -351,3 +452,640 @@ macro_rules! impl_steady_vec_into_iter {
 }
 impl_steady_vec_into_iter!(SteadyVecIntoIter);
 impl_steady_vec_into_iter!(BoxedSteadyVecIntoIter);
+
+/// A borrowing Iterator over segments
+///
+/// An iterator that yields each initialised segment of the `SteadyVec` as a
+/// contiguous slice (from start to end). Created using
+/// [`SteadyVec::segments`].
+///
+/// This iterator also implements [`FusedIterator`], [`ExactSizeIterator`], &
+/// [`DoubleEndedIterator`].
+pub struct Segments<'s, E: 's> {
+  steady_vec: &'s SteadyVec<E>,
+  // next subarray to yield
+  next: usize,
+  // the last subarray to yield + 1 (exclusive)
+  end: usize,
+}
+
+impl<'s, E> Segments<'s, E> {
+  pub(crate) fn new(steady_vec: &'s SteadyVec<E>) -> Self {
+    Segments {
+      next: 0,
+      end: initialised_subarray_count(steady_vec.len),
+      steady_vec,
+    }
+  }
+
+  fn segment(&self, subarray_n: usize) -> &'s [E] {
+    // safety: `self.next..self.end` are initialised subarrays.
+    unsafe { self.steady_vec.segment_slice(subarray_n) }
+  }
+}
+
+impl<'s, E> Iterator for Segments<'s, E> {
+  type Item = &'s [E];
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.next >= self.end {
+      return None;
+    }
+
+    let segment = self.segment(self.next);
+    self.next += 1;
+    Some(segment)
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let remaining = self.end - self.next;
+    (remaining, Some(remaining))
+  }
+}
+
+impl<'s, E> FusedIterator for Segments<'s, E> {}
+
+impl<'s, E> ExactSizeIterator for Segments<'s, E> {
+  fn len(&self) -> usize {
+    let (lower, _) = self.size_hint();
+    lower
+  }
+}
+
+impl<'s, E> DoubleEndedIterator for Segments<'s, E> {
+  fn next_back(&mut self) -> Option<Self::Item> {
+    if self.next >= self.end {
+      return None;
+    }
+
+    self.end -= 1;
+    Some(self.segment(self.end))
+  }
+}
+
+/// A mutably borrowing Iterator over segments
+///
+/// An iterator that yields each initialised segment of the `SteadyVec` as a
+/// contiguous mutable slice (from start to end). Created using
+/// [`SteadyVec::segments_mut`].
+///
+/// This iterator also implements [`FusedIterator`], [`ExactSizeIterator`], &
+/// [`DoubleEndedIterator`].
+//
+// # Safety
+//
+// Holds a shared reference for the same reason as `SteadySliceMut`; each
+// subarray is yielded at most once.
+pub struct SegmentsMut<'s, E: 's> {
+  steady_vec: &'s SteadyVec<E>,
+  // next subarray to yield
+  next: usize,
+  // the last subarray to yield + 1 (exclusive)
+  end: usize,
+  _lifetime: PhantomData<&'s mut E>,
+}
+
+// safety: as for `SteadyVecIterMut`
+unsafe impl<'s, E: Send> Send for SegmentsMut<'s, E> {}
+unsafe impl<'s, E: Sync> Sync for SegmentsMut<'s, E> {}
+
+impl<'s, E> SegmentsMut<'s, E> {
+  pub(crate) fn new(steady_vec: &'s mut SteadyVec<E>) -> Self {
+    SegmentsMut {
+      next: 0,
+      end: initialised_subarray_count(steady_vec.len),
+      steady_vec,
+      _lifetime: PhantomData,
+    }
+  }
+
+  fn segment(&self, subarray_n: usize) -> &'s mut [E] {
+    let len = Segment::new(subarray_n, self.steady_vec.len).len();
+    // safety:
+    // - the value of `self.steady_vec.len` tells us the subarray exists, and
+    //   that it contains `len` initialised elements.
+    // - each subarray is only yielded once (see the note above).
+    unsafe {
+      let subarray = self.steady_vec.subarrays[subarray_n]
+        .as_ref()
+        .unwrap_unchecked();
+
+      slice::from_raw_parts_mut(subarray.element_ptr(0), len)
+    }
+  }
+}
+
+impl<'s, E> Iterator for SegmentsMut<'s, E> {
+  type Item = &'s mut [E];
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.next >= self.end {
+      return None;
+    }
+
+    let segment = self.segment(self.next);
+    self.next += 1;
+    Some(segment)
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let remaining = self.end - self.next;
+    (remaining, Some(remaining))
+  }
+}
+
+impl<'s, E> FusedIterator for SegmentsMut<'s, E> {}
+
+impl<'s, E> ExactSizeIterator for SegmentsMut<'s, E> {
+  fn len(&self) -> usize {
+    let (lower, _) = self.size_hint();
+    lower
+  }
+}
+
+impl<'s, E> DoubleEndedIterator for SegmentsMut<'s, E> {
+  fn next_back(&mut self) -> Option<Self::Item> {
+    if self.next >= self.end {
+      return None;
+    }
+
+    self.end -= 1;
+    Some(self.segment(self.end))
+  }
+}
+
+/// An Iterator over segment metadata
+///
+/// An iterator that yields a [`Segment`] describing each initialised segment
+/// of the `SteadyVec` (from start to end). It does not borrow the
+/// `SteadyVec`. Created using [`SteadyVec::segment_ranges`].
+///
+/// This iterator also implements [`FusedIterator`], [`ExactSizeIterator`], &
+/// [`DoubleEndedIterator`].
+#[derive(Clone, Debug)]
+pub struct SegmentRanges {
+  len: usize,
+  // next subarray to yield
+  next: usize,
+  // the last subarray to yield + 1 (exclusive)
+  end: usize,
+}
+
+impl SegmentRanges {
+  pub(crate) fn new(len: usize) -> Self {
+    SegmentRanges {
+      len,
+      next: 0,
+      end: initialised_subarray_count(len),
+    }
+  }
+}
+
+impl Iterator for SegmentRanges {
+  type Item = Segment;
+
+  fn next(&mut self) -> Option<Segment> {
+    if self.next >= self.end {
+      return None;
+    }
+
+    let segment = Segment::new(self.next, self.len);
+    self.next += 1;
+    Some(segment)
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let remaining = self.end - self.next;
+    (remaining, Some(remaining))
+  }
+}
+
+impl FusedIterator for SegmentRanges {}
+
+impl ExactSizeIterator for SegmentRanges {
+  fn len(&self) -> usize {
+    let (lower, _) = self.size_hint();
+    lower
+  }
+}
+
+impl DoubleEndedIterator for SegmentRanges {
+  fn next_back(&mut self) -> Option<Segment> {
+    if self.next >= self.end {
+      return None;
+    }
+
+    self.end -= 1;
+    Some(Segment::new(self.end, self.len))
+  }
+}
+
+/// A draining Iterator
+///
+/// An iterator that moves each value in a range out of the `SteadyVec` (from
+/// start to end). When the iterator is dropped, any remaining elements in the
+/// range are dropped, and the elements after the range are shifted down to
+/// fill the gap. Created using [`SteadyVec::drain`].
+///
+/// This iterator also implements [`FusedIterator`], [`ExactSizeIterator`], &
+/// [`DoubleEndedIterator`].
+//
+// # Safety
+//
+// While the `Drain` exists the `len` of the `SteadyVec` is set to the start of
+// the drained range, so that if the `Drain` is leaked (e.g. `mem::forget`) the
+// `SteadyVec` remains valid; the drained range and the tail are leaked rather
+// than being dropped twice.
+//
+// Only the elements from `self.next..self.end` and the tail
+// `self.tail_start..self.tail_start + self.tail_len` are initialised.
+pub struct Drain<'s, E: 's> {
+  steady_vec: &'s mut SteadyVec<E>,
+  // next index to read
+  next: usize,
+  // the last index to read + 1 (exclusive)
+  end: usize,
+  // the first index after the drained range
+  tail_start: usize,
+  // the number of elements after the drained range
+  tail_len: usize,
+}
+
+impl<'s, E> Drain<'s, E> {
+  /// `range` must be in bounds of `steady_vec.len`.
+  pub(crate) fn new(
+    steady_vec: &'s mut SteadyVec<E>,
+    range: Range<usize>,
+  ) -> Self {
+    let tail_len = steady_vec.len - range.end;
+    steady_vec.len = range.start;
+
+    Drain {
+      steady_vec,
+      next: range.start,
+      end: range.end,
+      tail_start: range.end,
+      tail_len,
+    }
+  }
+}
+
+impl<'s, E> Iterator for Drain<'s, E> {
+  type Item = E;
+
+  fn next(&mut self) -> Option<E> {
+    if self.next >= self.end {
+      return None;
+    }
+
+    // safety:
+    // - the element at `self.next` is initialised (see the note above).
+    // - after this the element is uninitialised, and `self.next` is advanced
+    //   past it.
+    let element = unsafe { self.steady_vec.element_ptr(self.next).read() };
+    self.next += 1;
+
+    Some(element)
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let remaining = self.end - self.next;
+    (remaining, Some(remaining))
+  }
+}
+
+impl<'s, E> FusedIterator for Drain<'s, E> {}
+
+impl<'s, E> ExactSizeIterator for Drain<'s, E> {
+  fn len(&self) -> usize {
+    let (lower, _) = self.size_hint();
+    lower
+  }
+}
+
+impl<'s, E> DoubleEndedIterator for Drain<'s, E> {
+  fn next_back(&mut self) -> Option<E> {
+    if self.next >= self.end {
+      return None;
+    }
+
+    self.end -= 1;
+    // safety: as above, `self.end` is decremented past the element.
+    let element = unsafe { self.steady_vec.element_ptr(self.end).read() };
+
+    Some(element)
+  }
+}
+
+impl<'s, E> Drop for Drain<'s, E> {
+  fn drop(&mut self) {
+    // Shifts the tail down, even if a destructor panics while dropping the
+    // remaining elements.
+    struct DropGuard<'r, 's, E>(&'r mut Drain<'s, E>);
+
+    impl<'r, 's, E> Drop for DropGuard<'r, 's, E> {
+      fn drop(&mut self) {
+        let drain = &mut *self.0;
+        let start = drain.steady_vec.len;
+        // safety:
+        // - the tail is initialised, and the subarrays for the whole range
+        //   `start..tail_start + tail_len` exist.
+        // - after the copy, `start..start + tail_len` is initialised.
+        unsafe {
+          drain.steady_vec.copy_within_unchecked(
+            drain.tail_start,
+            start,
+            drain.tail_len,
+          );
+        }
+        drain.steady_vec.len = start + drain.tail_len;
+      }
+    }
+
+    let remaining = self.next..self.end;
+    self.next = self.end;
+
+    let guard = DropGuard(self);
+    // safety: the remaining elements are initialised, and are not accessed
+    // again since `next` was advanced to `end`.
+    unsafe { guard.0.steady_vec.drop_range_in_place(remaining) };
+  }
+}
+
+impl<'s, E> Drain<'s, E> {
+  /// Fill the gap between the end of the `SteadyVec` and the tail with
+  /// elements from `replace_with`
+  ///
+  /// Returns `true` if the gap was filled entirely.
+  ///
+  /// The drained range must have been fully consumed.
+  fn fill(&mut self, replace_with: &mut impl Iterator<Item = E>) -> bool {
+    let mut filler = RawCursor::new(self.steady_vec.len);
+    while self.steady_vec.len < self.tail_start {
+      let Some(element) = replace_with.next() else {
+        return false;
+      };
+      // safety:
+      // - the slot is in the gap below `tail_start`, so the subarray exists
+      //   and the slot is uninitialised.
+      // - `len` is incremented after each element is written, so if
+      //   `replace_with` panics the written elements are kept.
+      unsafe { filler.ptr(self.steady_vec).write(element) };
+      filler.advance();
+      self.steady_vec.len += 1;
+    }
+    true
+  }
+
+  /// Move the tail up by `additional` positions, widening the gap
+  ///
+  /// # Panics
+  ///
+  /// Panics if the new length would exceed [`SteadyVec::MAX_CAPACITY`].
+  fn move_tail(&mut self, additional: usize) {
+    // `additional` may come from an untrusted `size_hint`, so guard against
+    // overflow before reserving.
+    let required_len = self
+      .tail_start
+      .checked_add(additional)
+      .and_then(|new_tail_start| new_tail_start.checked_add(self.tail_len))
+      .unwrap_or_else(|| {
+        panic!(
+          "capacity would exceed maximum: {max_capacity}",
+          max_capacity = SteadyVec::<E>::MAX_CAPACITY
+        )
+      });
+    let new_tail_start = self.tail_start + additional;
+    self.steady_vec.reserve(required_len - self.steady_vec.len);
+
+    // safety:
+    // - the subarrays for the new tail position were just reserved.
+    // - the tail is initialised, and the gap is uninitialised.
+    unsafe {
+      self.steady_vec.copy_within_unchecked(
+        self.tail_start,
+        new_tail_start,
+        self.tail_len,
+      );
+    }
+    self.tail_start = new_tail_start;
+  }
+}
+
+/// A splicing Iterator
+///
+/// An iterator that moves each value in a range out of the `SteadyVec` (from
+/// start to end), like [`Drain`]. When the iterator is dropped, the range is
+/// replaced with the elements from `replace_with`. Created using
+/// [`SteadyVec::splice`].
+///
+/// If the lower bound of `replace_with`'s `size_hint` is exact, the elements
+/// after the range are moved at most once. Otherwise any further replacement
+/// elements are collected into a temporary `Vec` first.
+///
+/// This iterator also implements [`ExactSizeIterator`], &
+/// [`DoubleEndedIterator`].
+pub struct Splice<'s, I: Iterator + 's> {
+  drain: Drain<'s, I::Item>,
+  replace_with: I,
+}
+
+impl<'s, I: Iterator> Splice<'s, I> {
+  pub(crate) fn new(drain: Drain<'s, I::Item>, replace_with: I) -> Self {
+    Splice {
+      drain,
+      replace_with,
+    }
+  }
+}
+
+impl<'s, I: Iterator> Iterator for Splice<'s, I> {
+  type Item = I::Item;
+
+  fn next(&mut self) -> Option<I::Item> {
+    self.drain.next()
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.drain.size_hint()
+  }
+}
+
+impl<'s, I: Iterator> ExactSizeIterator for Splice<'s, I> {
+  fn len(&self) -> usize {
+    self.drain.len()
+  }
+}
+
+impl<'s, I: Iterator> DoubleEndedIterator for Splice<'s, I> {
+  fn next_back(&mut self) -> Option<I::Item> {
+    self.drain.next_back()
+  }
+}
+
+impl<'s, I: Iterator> Drop for Splice<'s, I> {
+  fn drop(&mut self) {
+    // drop any elements remaining in the drained range
+    self.drain.by_ref().for_each(drop);
+
+    if self.drain.tail_len == 0 {
+      // There is no tail to move, so we can push directly.
+      self.drain.steady_vec.extend(self.replace_with.by_ref());
+      return;
+    }
+
+    // First fill the range left by the drain.
+    if !self.drain.fill(&mut self.replace_with) {
+      return;
+    }
+
+    // There may be more elements; use the lower bound as an estimate.
+    let (lower_bound, _) = self.replace_with.size_hint();
+    if lower_bound > 0 {
+      self.drain.move_tail(lower_bound);
+      if !self.drain.fill(&mut self.replace_with) {
+        return;
+      }
+    }
+
+    // Collect any remaining elements, so that we know exactly how far to move
+    // the tail.
+    let mut collected = self
+      .replace_with
+      .by_ref()
+      .collect::<Vec<I::Item>>()
+      .into_iter();
+    if collected.len() > 0 {
+      self.drain.move_tail(collected.len());
+      let filled = self.drain.fill(&mut collected);
+      debug_assert!(filled);
+      debug_assert_eq!(collected.len(), 0);
+    }
+
+    // The `Drain`'s destructor moves the tail back into place, if needed.
+  }
+}
+
+/// A filtering & draining Iterator
+///
+/// An iterator that visits each value in a range of the `SteadyVec` (from
+/// start to end), and moves it out of the `SteadyVec` if the predicate returns
+/// `true`. The retained elements are shifted down over the removed ones as the
+/// iterator advances, keeping their original order. Created using
+/// [`SteadyVec::extract_if`].
+///
+/// If the iterator is dropped before it is fully consumed, the remaining
+/// elements are retained. If the predicate panics, the element it was called
+/// on is retained.
+///
+/// This iterator also implements [`FusedIterator`].
+//
+// # Safety
+//
+// While the `ExtractIf` exists the `len` of the `SteadyVec` is set to 0, so
+// that if the `ExtractIf` is leaked the `SteadyVec` remains valid; the
+// elements are leaked rather than being dropped twice.
+//
+// The elements from `0..write.index()` and `read.index()..original_len` are
+// initialised, and `read.index() - write.index() == deleted`.
+pub struct ExtractIf<'s, E: 's, F> {
+  steady_vec: &'s mut SteadyVec<E>,
+  // next index to visit
+  read: RawCursor<E>,
+  // the first hole left by removed elements, if `deleted > 0`
+  write: RawCursor<E>,
+  // the last index to visit + 1 (exclusive)
+  end: usize,
+  // the number of elements removed so far
+  deleted: usize,
+  original_len: usize,
+  pred: F,
+}
+
+// safety: `ExtractIf` behaves like a `&mut SteadyVec<E>`; the `RawCursor`s
+// only cache pointers into it.
+unsafe impl<'s, E: Send, F: Send> Send for ExtractIf<'s, E, F> {}
+unsafe impl<'s, E: Sync, F: Sync> Sync for ExtractIf<'s, E, F> {}
+
+impl<'s, E, F> ExtractIf<'s, E, F> {
+  /// `range` must be in bounds of `steady_vec.len`.
+  pub(crate) fn new(
+    steady_vec: &'s mut SteadyVec<E>,
+    range: Range<usize>,
+    pred: F,
+  ) -> Self {
+    let original_len = steady_vec.len;
+    steady_vec.len = 0;
+
+    ExtractIf {
+      steady_vec,
+      read: RawCursor::new(range.start),
+      write: RawCursor::new(range.start),
+      end: range.end,
+      deleted: 0,
+      original_len,
+      pred,
+    }
+  }
+}
+
+impl<'s, E, F> Iterator for ExtractIf<'s, E, F>
+where
+  F: FnMut(&mut E) -> bool,
+{
+  type Item = E;
+
+  fn next(&mut self) -> Option<E> {
+    while self.read.index() < self.end {
+      // safety:
+      // - `read` is below `end`, so the subarray exists & the element is
+      //   initialised.
+      // - `read` is only advanced after the predicate returns, so if it
+      //   panics the element is retained and shifted down by `drop`.
+      // - `write` trails `read` by `deleted`, and points to a hole whenever
+      //   `deleted > 0`.
+      unsafe {
+        let current = self.read.ptr(self.steady_vec);
+        let extract = (self.pred)(&mut *current);
+        self.read.advance();
+
+        if extract {
+          self.deleted += 1;
+          return Some(current.read());
+        }
+        if self.deleted > 0 {
+          let hole = self.write.ptr(self.steady_vec);
+          ptr::copy_nonoverlapping(current, hole, 1);
+        }
+        self.write.advance();
+      }
+    }
+    None
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (0, Some(self.end - self.read.index()))
+  }
+}
+
+impl<'s, E, F> FusedIterator for ExtractIf<'s, E, F> where
+  F: FnMut(&mut E) -> bool
+{
+}
+
+impl<'s, E, F> Drop for ExtractIf<'s, E, F> {
+  fn drop(&mut self) {
+    let read = self.read.index();
+    // safety:
+    // - the elements from `read..original_len` are initialised, and the holes
+    //   left by removed elements are directly below them.
+    unsafe {
+      self.steady_vec.copy_within_unchecked(
+        read,
+        read - self.deleted,
+        self.original_len - read,
+      );
+    }
+    self.steady_vec.len = self.original_len - self.deleted;
+  }
+}
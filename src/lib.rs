@@ -1,22 +1,34 @@
 #![doc = include_str!("../README.md")]
 
+mod cmp;
+mod convert;
+pub mod cursor;
 pub mod iter;
 mod manual_heap_array_vec;
+mod raw_cursor;
+mod search;
+pub mod slice;
+mod sort;
 #[cfg(any(test, doctest))]
 mod tests;
+mod write;
 
 use {
   self::{
+    cursor::{Cursor, CursorMut},
     iter::{
-      BoxedSteadyVecIntoIter, SteadyVecIntoIter, SteadyVecIter,
-      SteadyVecIterMut,
+      BoxedSteadyVecIntoIter, Drain, ExtractIf, SegmentRanges, Segments,
+      SegmentsMut, Splice, SteadyVecIntoIter, SteadyVecIter, SteadyVecIterMut,
     },
     manual_heap_array_vec::ManualHeapArrayVec,
+    raw_cursor::RawCursor,
+    slice::{SteadySlice, SteadySliceMut},
   },
   ::core::{
+    fmt,
     iter::zip,
     mem::MaybeUninit,
-    ops::{Index, IndexMut},
+    ops::{Bound, Index, IndexMut, Range, RangeBounds},
     ptr,
   },
 };
@@ -113,6 +125,193 @@ pub(crate) fn index_metadata(index: usize) -> IndexMetadata {
   }
 }
 
+/// A run of indices which are contiguous in memory, i.e. stored within a
+/// single subarray
+pub(crate) struct SubarrayRun {
+  /// subarray number
+  subarray_n: usize,
+  /// index into the subarray of the first element in the run
+  first_element: usize,
+  /// index into the subarray of the last element in the run (inclusive)
+  last_element: usize,
+}
+
+impl SubarrayRun {
+  /// The number of elements in the run
+  #[inline]
+  pub(crate) fn len(&self) -> usize {
+    self.last_element - self.first_element + 1
+  }
+}
+
+/// Splits the indices in `range` into runs that are each stored within a
+/// single subarray, in order
+///
+/// Yields nothing if the range is empty.
+#[inline]
+pub(crate) fn subarray_runs(
+  range: Range<usize>,
+) -> impl DoubleEndedIterator<Item = SubarrayRun> {
+  // first and last indices in the range (inclusive)
+  let first_index_meta = index_metadata(range.start);
+  let last_index_meta = index_metadata(range.end.max(1) - 1);
+
+  let subarrays = if range.is_empty() {
+    0..0
+  } else {
+    first_index_meta.subarray_n..(last_index_meta.subarray_n + 1)
+  };
+  subarrays.map(move |subarray_n| {
+    let first_element = if subarray_n == first_index_meta.subarray_n {
+      first_index_meta.element
+    } else {
+      0
+    };
+    let last_element = if subarray_n == last_index_meta.subarray_n {
+      last_index_meta.element
+    } else {
+      subarray_capacity(subarray_n) - 1
+    };
+
+    SubarrayRun {
+      subarray_n,
+      first_element,
+      last_element,
+    }
+  })
+}
+
+/// The number of indices from `index` to the end of its subarray (inclusive)
+#[inline]
+pub(crate) fn indices_until_subarray_end(index: usize) -> usize {
+  let (_, last_index) = subarray_index_range(index_to_subarray_n(index));
+  last_index + 1 - index
+}
+
+/// The number of indices from the start of the subarray containing `index`
+/// up to `index` (inclusive)
+#[inline]
+pub(crate) fn indices_since_subarray_start(index: usize) -> usize {
+  index_metadata(index).element + 1
+}
+
+/// The number of subarrays that contain initialised elements, for a
+/// `SteadyVec` of length `len`
+#[inline]
+pub(crate) fn initialised_subarray_count(len: usize) -> usize {
+  if len == 0 {
+    0
+  } else {
+    index_to_subarray_n(len - 1) + 1
+  }
+}
+
+/// Resolves `range` into a `start..end` pair of indices, for a collection of
+/// length `len`
+///
+/// # Panics
+///
+/// Panics if the start of the range is greater than the end, or if the end is
+/// greater than `len`.
+#[inline]
+pub(crate) fn resolve_range(
+  range: impl RangeBounds<usize>,
+  len: usize,
+) -> Range<usize> {
+  let start = match range.start_bound() {
+    Bound::Included(&start) => start,
+    Bound::Excluded(&start) => {
+      start.checked_add(1).expect("range start is out of bounds")
+    },
+    Bound::Unbounded => 0,
+  };
+  let end = match range.end_bound() {
+    Bound::Included(&end) => {
+      end.checked_add(1).expect("range end is out of bounds")
+    },
+    Bound::Excluded(&end) => end,
+    Bound::Unbounded => len,
+  };
+
+  if start > end {
+    panic!("range start is greater than end, start: {start}, end: {end}");
+  }
+  if end > len {
+    panic!("range is out of bounds, start: {start}, end: {end}, len: {len}");
+  }
+
+  start..end
+}
+
+/// The error returned by [`SteadyVec::get_many_mut`]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GetManyMutError {
+  /// An index was greater than or equal to the length
+  IndexOutOfBounds,
+  /// The same index was given more than once
+  OverlappingIndices,
+}
+
+impl fmt::Display for GetManyMutError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let message = match self {
+      GetManyMutError::IndexOutOfBounds => "an index is out of bounds",
+      GetManyMutError::OverlappingIndices => "there were overlapping indices",
+    };
+    f.write_str(message)
+  }
+}
+
+impl ::std::error::Error for GetManyMutError {}
+
+/// Describes the position of a single segment (subarray) within a `SteadyVec`
+///
+/// Each segment is stored contiguously in memory. Created using
+/// [`SteadyVec::segment_ranges`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Segment {
+  subarray_n: usize,
+  range: Range<usize>,
+}
+
+impl Segment {
+  /// The subarray number of this segment
+  pub fn subarray_n(&self) -> usize {
+    self.subarray_n
+  }
+
+  /// The range of indices (into the `SteadyVec`) of the initialised elements
+  /// stored in this segment
+  pub fn range(&self) -> Range<usize> {
+    self.range.clone()
+  }
+
+  /// The number of initialised elements stored in this segment
+  pub fn len(&self) -> usize {
+    self.range.len()
+  }
+
+  /// Returns `true` if this segment contains no initialised elements
+  pub fn is_empty(&self) -> bool {
+    self.range.is_empty()
+  }
+
+  /// The total number of elements this segment can hold
+  pub fn capacity(&self) -> usize {
+    subarray_capacity(self.subarray_n)
+  }
+
+  /// Describes subarray number `n`, for a `SteadyVec` of length `len`
+  pub(crate) fn new(subarray_n: usize, len: usize) -> Self {
+    let (first_index, last_index) = subarray_index_range(subarray_n);
+    let end = (last_index + 1).min(len).max(first_index);
+    Segment {
+      subarray_n,
+      range: first_index..end,
+    }
+  }
+}
+
 impl<E> SteadyVec<E> {
   #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
   /// The maximum capacity of a `SteadyVec`, 2³² on 64 bit architectures
@@ -172,7 +371,7 @@ impl<E> SteadyVec<E> {
   ///
   /// Panics if the new capacity would exceed [`Self::MAX_CAPACITY`].
   pub fn reserve(&mut self, additional: usize) {
-    let new_min_capacity = self.len + additional;
+    let new_min_capacity = self.len.saturating_add(additional);
     if new_min_capacity > Self::MAX_CAPACITY {
       panic!(
         "capacity: {new_min_capacity} would exceed maximum: {max_capacity}",
@@ -180,9 +379,13 @@ impl<E> SteadyVec<E> {
       );
     }
 
+    if additional == 0 {
+      return;
+    }
+
     let required_subarray_n = index_to_subarray_n(new_min_capacity - 1);
-    let last_subarray_n = index_to_subarray_n(self.len - 1);
-    for subarray_n in (last_subarray_n + 1)..=required_subarray_n {
+    let first_subarray_n = initialised_subarray_count(self.len);
+    for subarray_n in first_subarray_n..=required_subarray_n {
       if self.subarrays[subarray_n].is_none() {
         self.subarrays[subarray_n] =
           Some(ManualHeapArrayVec::new(subarray_capacity(subarray_n)));
@@ -291,6 +494,231 @@ impl<E> SteadyVec<E> {
     Some(element)
   }
 
+  /// Moves all the elements of `other` onto the end of `self`, leaving
+  /// `other` empty
+  ///
+  /// The elements are copied in bulk, a run of contiguous elements at a time.
+  /// The capacity of `other` is not changed.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the new length would exceed [`Self::MAX_CAPACITY`].
+  pub fn append(&mut self, other: &mut Self) {
+    let count = other.len;
+    self.reserve(count);
+
+    // safety:
+    // - `reserve` ensures the destination subarrays exist.
+    // - `other.len` promises the source elements are initialised; setting it
+    //   to 0 afterwards prevents them from being used again.
+    unsafe { other.copy_to_unchecked(0, self, self.len, count) };
+    other.len = 0;
+    self.len += count;
+  }
+
+  /// Splits the `SteadyVec` into two at `at`
+  ///
+  /// Returns a newly allocated `SteadyVec` containing the elements from
+  /// `at..len`, while `self` is left containing the elements from `0..at`. The
+  /// capacity of `self` is not changed.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `at` is greater than the length.
+  pub fn split_off(&mut self, at: usize) -> Self {
+    if at > self.len {
+      panic!(
+        "index is out of bounds, at: {at}, len: {len}",
+        len = self.len
+      );
+    }
+
+    let count = self.len - at;
+    let mut other = SteadyVec::new();
+    other.reserve(count);
+
+    // safety:
+    // - `reserve` ensures the destination subarrays exist.
+    // - `self.len` promises the source elements are initialised; truncating it
+    //   afterwards prevents them from being used again.
+    unsafe { self.copy_to_unchecked(at, &mut other, 0, count) };
+    self.len = at;
+    other.len = count;
+
+    other
+  }
+
+  /// Get a raw pointer to the element at `index`
+  ///
+  /// The pointer is derived from the subarray allocation, not from `self`, so
+  /// pointers to distinct elements can be used to create disjoint mutable
+  /// references.
+  ///
+  /// # Safety
+  ///
+  /// - the subarray containing `index` must exist; this is always the case
+  ///   when `index` is less than the length.
+  #[inline]
+  pub(crate) unsafe fn element_ptr(&self, index: usize) -> *mut E {
+    let index_metadata = index_metadata(index);
+    unsafe {
+      let subarray = self
+        .subarrays
+        .get_unchecked(index_metadata.subarray_n)
+        .as_ref()
+        .unwrap_unchecked();
+
+      subarray.element_ptr(index_metadata.element)
+    }
+  }
+
+  /// Drop in place all elements in `range`
+  ///
+  /// If a destructor panics, the remaining elements in later subarrays are
+  /// leaked.
+  ///
+  /// # Safety
+  ///
+  /// - the elements in `range` must be initialised.
+  /// - after calling this, the elements in `range` will be uninitialised; the
+  ///   caller is responsible for ensuring they are not used again.
+  pub(crate) unsafe fn drop_range_in_place(&mut self, range: Range<usize>) {
+    for run in subarray_runs(range) {
+      // safety: the caller promises the elements are initialised, so the
+      // subarray exists.
+      unsafe {
+        let subarray =
+          self.subarrays[run.subarray_n].as_mut().unwrap_unchecked();
+        subarray.drop_in_place(run.first_element, run.last_element);
+      }
+    }
+  }
+
+  /// Bitwise copy `count` elements from `src..src + count` to
+  /// `dst..dst + count`, like `ptr::copy`
+  ///
+  /// The ranges may overlap. The copy is done in runs, so that each run is
+  /// contiguous in both the source and destination subarrays.
+  ///
+  /// # Safety
+  ///
+  /// - the subarrays for both ranges must exist.
+  /// - the elements in the source range should be initialised; afterwards the
+  ///   elements in the source range which are not part of the destination
+  ///   range are logically uninitialised.
+  pub(crate) unsafe fn copy_within_unchecked(
+    &mut self,
+    src: usize,
+    dst: usize,
+    count: usize,
+  ) {
+    if src == dst || count == 0 {
+      return;
+    }
+
+    if dst < src {
+      // Copy runs from front to back, so that the source elements are read
+      // before the destination overwrites them.
+      let mut copied = 0;
+      while copied < count {
+        let (src, dst) = (src + copied, dst + copied);
+        let run = (count - copied)
+          .min(indices_until_subarray_end(src))
+          .min(indices_until_subarray_end(dst));
+        // safety:
+        // - the caller promises the subarrays exist.
+        // - the run does not cross the end of either subarray.
+        unsafe {
+          ptr::copy(self.element_ptr(src), self.element_ptr(dst), run)
+        };
+        copied += run;
+      }
+    } else {
+      // Copy runs from back to front, for the same reason.
+      let mut remaining = count;
+      while remaining > 0 {
+        let (src_last, dst_last) = (src + remaining - 1, dst + remaining - 1);
+        let run = remaining
+          .min(indices_since_subarray_start(src_last))
+          .min(indices_since_subarray_start(dst_last));
+        // safety: as above
+        unsafe {
+          ptr::copy(
+            self.element_ptr(src_last + 1 - run),
+            self.element_ptr(dst_last + 1 - run),
+            run,
+          )
+        };
+        remaining -= run;
+      }
+    }
+  }
+
+  /// Bitwise copy `count` elements from `src..src + count` in `self` to
+  /// `dst..dst + count` in `dst_vec`, like `ptr::copy_nonoverlapping`
+  ///
+  /// # Safety
+  ///
+  /// - the subarrays for both ranges must exist.
+  /// - the elements in the source range should be initialised; afterwards they
+  ///   are logically uninitialised.
+  pub(crate) unsafe fn copy_to_unchecked(
+    &self,
+    src: usize,
+    dst_vec: &mut SteadyVec<E>,
+    dst: usize,
+    count: usize,
+  ) {
+    let mut copied = 0;
+    while copied < count {
+      let (src, dst) = (src + copied, dst + copied);
+      let run = (count - copied)
+        .min(indices_until_subarray_end(src))
+        .min(indices_until_subarray_end(dst));
+      // safety:
+      // - the caller promises the subarrays exist.
+      // - the run does not cross the end of either subarray.
+      unsafe {
+        ptr::copy_nonoverlapping(
+          self.element_ptr(src),
+          dst_vec.element_ptr(dst),
+          run,
+        )
+      };
+      copied += run;
+    }
+  }
+
+  /// Bitwise copy `count` elements from the contiguous memory at `src` into
+  /// `dst..dst + count`, like `ptr::copy_nonoverlapping`
+  ///
+  /// # Safety
+  ///
+  /// - the subarrays for the destination range must exist.
+  /// - `src` must be valid for reads of `count` elements, and must not overlap
+  ///   the destination range.
+  pub(crate) unsafe fn copy_from_ptr_unchecked(
+    &mut self,
+    src: *const E,
+    dst: usize,
+    count: usize,
+  ) {
+    let mut copied = 0;
+    for run in subarray_runs(dst..dst + count) {
+      // safety: as above
+      unsafe {
+        let subarray =
+          self.subarrays[run.subarray_n].as_ref().unwrap_unchecked();
+        ptr::copy_nonoverlapping(
+          src.add(copied),
+          subarray.element_ptr(run.first_element),
+          run.len(),
+        );
+      }
+      copied += run.len();
+    }
+  }
+
   /// Get the element at the index
   pub fn get(&self, index: usize) -> Option<&E> {
     if index >= self.len {
@@ -339,6 +767,43 @@ impl<E> SteadyVec<E> {
     Some(element)
   }
 
+  /// Mutably get the elements at several distinct indices at once
+  ///
+  /// Since elements never move, the returned references remain valid
+  /// independently of one another.
+  ///
+  /// O(N²) time complexity, to check that the indices are distinct.
+  ///
+  /// # Errors
+  ///
+  /// - Returns [`GetManyMutError::IndexOutOfBounds`] if any index is greater
+  ///   than or equal to the length.
+  /// - Returns [`GetManyMutError::OverlappingIndices`] if any index is given
+  ///   more than once.
+  pub fn get_many_mut<const N: usize>(
+    &mut self,
+    indices: [usize; N],
+  ) -> Result<[&mut E; N], GetManyMutError> {
+    for (i, &index) in indices.iter().enumerate() {
+      if index >= self.len {
+        return Err(GetManyMutError::IndexOutOfBounds);
+      }
+      if indices[..i].contains(&index) {
+        return Err(GetManyMutError::OverlappingIndices);
+      }
+    }
+
+    let steady_vec: &Self = self;
+    // safety:
+    // - every index is less than `len`, so each element exists and is
+    //   initialised.
+    // - the indices are distinct, and the pointers are derived from the
+    //   subarray allocations without creating intermediate references, so
+    //   the mutable references never alias.
+    // - `self` is borrowed mutably for the lifetime of the references.
+    Ok(indices.map(|index| unsafe { &mut *steady_vec.element_ptr(index) }))
+  }
+
   /// Insert an element at `index`, shifting all following elements to the
   /// right
   ///
@@ -424,6 +889,54 @@ impl<E> SteadyVec<E> {
     self.len += 1;
   }
 
+  /// Insert all the elements of `iter` at position `index`, shifting all
+  /// following elements to the right
+  ///
+  /// The following elements are moved once, directly into their final
+  /// position, if the lower bound of `iter`'s `size_hint` is exact. The new
+  /// elements are then written into the gap. Otherwise the elements beyond
+  /// the lower bound are collected first, so the following elements are moved
+  /// at most twice.
+  ///
+  /// O(n + k) time complexity, where k is the number of inserted elements.
+  ///
+  /// # Panics
+  ///
+  /// - Panics if `index` is greater than the length.
+  /// - Panics if the new capacity would exceed [`Self::MAX_CAPACITY`].
+  pub fn insert_many(
+    &mut self,
+    index: usize,
+    iter: impl IntoIterator<Item = E>,
+  ) {
+    if index > self.len {
+      panic!(
+        "index is out of bounds, index: {index}, len: {len}",
+        len = self.len
+      );
+    }
+
+    // dropping the empty `Splice` inserts the elements
+    drop(self.splice(index..index, iter));
+  }
+
+  /// Clone and insert all the elements of a slice at position `index`,
+  /// shifting all following elements to the right
+  ///
+  /// The following elements are moved once, see
+  /// [`insert_many`](Self::insert_many).
+  ///
+  /// # Panics
+  ///
+  /// - Panics if `index` is greater than the length.
+  /// - Panics if the new capacity would exceed [`Self::MAX_CAPACITY`].
+  pub fn insert_from_slice(&mut self, index: usize, other: &[E])
+  where
+    E: Clone,
+  {
+    self.insert_many(index, other.iter().cloned());
+  }
+
   /// Remove and return the element at `index`, shifting all following elements
   /// to the left
   ///
@@ -577,29 +1090,13 @@ impl<E> SteadyVec<E> {
     } else {
       unsafe {
         // safety:
-        // - `len` promises that the subarray for, and that the element at
-        //   `a` exists.
-        let a_ptr: *mut E = {
-          let meta = index_metadata(a_index);
-          let subarray =
-            self.subarrays[meta.subarray_n].as_mut().unwrap_unchecked();
-
-          subarray
-            .as_slice_mut(meta.element, meta.element)
-            .get_unchecked_mut(0)
-        };
-        // safety:
-        // - `len` promises that the subarray for, and that the element at
-        //   `b` exists.
-        let b_ptr: *mut E = {
-          let meta = index_metadata(b_index);
-          let subarray =
-            self.subarrays[meta.subarray_n].as_mut().unwrap_unchecked();
-
-          subarray
-            .as_slice_mut(meta.element, meta.element)
-            .get_unchecked_mut(0)
-        };
+        // - `len` promises that the subarrays for, and the elements at `a` &
+        //   `b` exist.
+        // - the pointers are derived from the subarray allocations without
+        //   creating intermediate references, so they remain valid even if
+        //   `a_index == b_index`.
+        let a_ptr: *mut E = self.element_ptr(a_index);
+        let b_ptr: *mut E = self.element_ptr(b_index);
 
         // safety: `a_ptr` & `b_ptr` are properly aligned and valid as above.
         // also note: `ptr::swap` allows them to overlap
@@ -608,6 +1105,150 @@ impl<E> SteadyVec<E> {
     }
   }
 
+  /// Reverses the order of the elements in place
+  pub fn reverse(&mut self) {
+    if self.len < 2 {
+      return;
+    }
+
+    let mut front = RawCursor::new(0);
+    let mut back = RawCursor::new(self.len - 1);
+    while front.index() < back.index() {
+      // safety: both indices are in bounds, and distinct.
+      unsafe { ptr::swap_nonoverlapping(front.ptr(self), back.ptr(self), 1) };
+      front.advance();
+      back.retreat();
+    }
+  }
+
+  /// Rotates the `SteadyVec` in place, such that the element at `mid` becomes
+  /// the first element
+  ///
+  /// The elements are moved using block swaps between contiguous runs, which
+  /// is O(n) time complexity.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `mid` is greater than the length.
+  pub fn rotate_left(&mut self, mid: usize) {
+    if mid > self.len {
+      panic!(
+        "index is out of bounds, mid: {mid}, len: {len}",
+        len = self.len
+      );
+    }
+
+    // safety: the whole range `0..len` is initialised.
+    unsafe { self.rotate_unchecked(mid, self.len - mid) };
+  }
+
+  /// Rotates the `SteadyVec` in place, such that the last `k` elements move to
+  /// the front
+  ///
+  /// The elements are moved using block swaps between contiguous runs, which
+  /// is O(n) time complexity.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `k` is greater than the length.
+  pub fn rotate_right(&mut self, k: usize) {
+    if k > self.len {
+      panic!("index is out of bounds, k: {k}, len: {len}", len = self.len);
+    }
+
+    // safety: the whole range `0..len` is initialised.
+    unsafe { self.rotate_unchecked(self.len - k, k) };
+  }
+
+  /// Rotates the range `0..left + right`, such that the `right` elements at
+  /// the end move in front of the `left` elements at the start
+  ///
+  /// # Safety
+  ///
+  /// - the elements in the range must be initialised.
+  pub(crate) unsafe fn rotate_unchecked(
+    &mut self,
+    mut left: usize,
+    mut right: usize,
+  ) {
+    let mut start = 0;
+    // Each block swap moves one block into its final position, and leaves a
+    // smaller rotation of the remaining elements.
+    while left > 0 && right > 0 {
+      // safety: the swapped ranges are disjoint, and within the range.
+      unsafe {
+        if left <= right {
+          self.swap_ranges_unchecked(start, start + left, left);
+          start += left;
+          right -= left;
+        } else {
+          self.swap_ranges_unchecked(
+            start + left - right,
+            start + left,
+            right,
+          );
+          left -= right;
+        }
+      }
+    }
+  }
+
+  /// Swap the elements in `a..a + count` with the elements in `b..b + count`
+  ///
+  /// The swap is done in runs, so that each run is contiguous in both
+  /// subarrays.
+  ///
+  /// # Safety
+  ///
+  /// - the subarrays for both ranges must exist.
+  /// - the ranges must not overlap.
+  pub(crate) unsafe fn swap_ranges_unchecked(
+    &mut self,
+    a: usize,
+    b: usize,
+    count: usize,
+  ) {
+    let mut swapped = 0;
+    while swapped < count {
+      let (a, b) = (a + swapped, b + swapped);
+      let run = (count - swapped)
+        .min(indices_until_subarray_end(a))
+        .min(indices_until_subarray_end(b));
+      // safety:
+      // - the caller promises the subarrays exist, and that the ranges don't
+      //   overlap.
+      // - the run does not cross the end of either subarray.
+      unsafe {
+        ptr::swap_nonoverlapping(self.element_ptr(a), self.element_ptr(b), run)
+      };
+      swapped += run;
+    }
+  }
+
+  /// Fills the `SteadyVec` with clones of `value`
+  ///
+  /// Each segment is filled as a contiguous slice.
+  pub fn fill(&mut self, value: E)
+  where
+    E: Clone,
+  {
+    let mut segments = self.segments_mut();
+    if let Some(last) = segments.next_back() {
+      for segment in segments {
+        segment.fill(value.clone());
+      }
+      last.fill(value);
+    }
+  }
+
+  /// Fills the `SteadyVec` with values returned by calling a closure
+  /// repeatedly, in order
+  pub fn fill_with(&mut self, mut f: impl FnMut() -> E) {
+    for segment in self.segments_mut() {
+      segment.fill_with(&mut f);
+    }
+  }
+
   /// Returns an iterator over each element of the collection
   pub fn iter(&self) -> SteadyVecIter<E> {
     SteadyVecIter::new(self)
@@ -618,13 +1259,504 @@ impl<E> SteadyVec<E> {
     SteadyVecIterMut::new(self)
   }
 
-  // pub fn retain(&mut self, f: impl FnMut(&E) -> bool) {
-  //   todo!()
-  // }
+  /// Returns a cursor pointing to `index`
+  ///
+  /// Moving the cursor to an adjacent element is O(1), since the cursor
+  /// caches the location of the current element.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `index` is greater than the length.
+  pub fn cursor(&self, index: usize) -> Cursor<E> {
+    if index > self.len {
+      panic!(
+        "index is out of bounds, index: {index}, len: {len}",
+        len = self.len
+      );
+    }
+    Cursor::new(self, index)
+  }
+
+  /// Returns a cursor pointing to `index`, which can modify the `SteadyVec`
+  ///
+  /// # Panics
+  ///
+  /// Panics if `index` is greater than the length.
+  pub fn cursor_mut(&mut self, index: usize) -> CursorMut<E> {
+    if index > self.len {
+      panic!(
+        "index is out of bounds, index: {index}, len: {len}",
+        len = self.len
+      );
+    }
+    CursorMut::new(self, index)
+  }
+
+  /// Returns an iterator over each initialised segment of the `SteadyVec`, as
+  /// contiguous slices
+  ///
+  /// The concatenation of the slices contains every element, in order. Only
+  /// the final slice may be shorter than the capacity of its subarray.
+  #[doc(alias = "as_slices")]
+  pub fn segments(&self) -> Segments<E> {
+    Segments::new(self)
+  }
+
+  /// Returns an iterator over each initialised segment of the `SteadyVec`, as
+  /// contiguous mutable slices
+  pub fn segments_mut(&mut self) -> SegmentsMut<E> {
+    SegmentsMut::new(self)
+  }
+
+  /// Get the initialised elements of subarray number `subarray_n` as a slice
+  ///
+  /// # Safety
+  ///
+  /// - `subarray_n` must be less than the number of initialised subarrays.
+  pub(crate) unsafe fn segment_slice(&self, subarray_n: usize) -> &[E] {
+    let len = Segment::new(subarray_n, self.len).len();
+    // safety:
+    // - the value of `self.len` tells us the subarray exists, and that it
+    //   contains `len` initialised elements (at least 1).
+    unsafe {
+      let subarray = self.subarrays[subarray_n].as_ref().unwrap_unchecked();
+
+      subarray.as_slice(0, len - 1)
+    }
+  }
+
+  /// Get the elements in `range` as contiguous slices, one per subarray run
+  ///
+  /// # Safety
+  ///
+  /// - the elements in `range` must be initialised.
+  pub(crate) unsafe fn run_slices(
+    &self,
+    range: Range<usize>,
+  ) -> impl DoubleEndedIterator<Item = &[E]> {
+    subarray_runs(range).map(move |run| {
+      // safety: the caller promises the elements of the run are initialised.
+      unsafe {
+        let subarray =
+          self.subarrays[run.subarray_n].as_ref().unwrap_unchecked();
+        subarray.as_slice(run.first_element, run.last_element)
+      }
+    })
+  }
+
+  /// Returns a borrowed view of the elements in `range`
+  ///
+  /// Since the elements are not stored contiguously, this returns a
+  /// [`SteadySlice`] rather than a `&[E]`. For the same reason, `SteadyVec`
+  /// cannot implement `Index<Range<usize>>`, which must return a reference.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the start of the range is greater than the end, or if the end
+  /// is greater than the length.
+  pub fn slice(&self, range: impl RangeBounds<usize>) -> SteadySlice<E> {
+    let range = resolve_range(range, self.len);
+    SteadySlice::new(self, range)
+  }
+
+  /// Returns a mutably borrowed view of the elements in `range`
+  ///
+  /// # Panics
+  ///
+  /// Panics if the start of the range is greater than the end, or if the end
+  /// is greater than the length.
+  pub fn slice_mut(
+    &mut self,
+    range: impl RangeBounds<usize>,
+  ) -> SteadySliceMut<E> {
+    let range = resolve_range(range, self.len);
+    SteadySliceMut::new(self, range)
+  }
+
+  /// Divides the `SteadyVec` into two mutable views at `mid`
+  ///
+  /// The first view contains the indices `0..mid`, and the second contains
+  /// `mid..len`. Since the views do not overlap, both may be used (and split
+  /// further) at the same time.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `mid` is greater than the length.
+  pub fn split_at_mut(
+    &mut self,
+    mid: usize,
+  ) -> (SteadySliceMut<E>, SteadySliceMut<E>) {
+    self.slice_mut(..).into_split_at_mut(mid)
+  }
+
+  /// Returns an iterator describing the position of each initialised segment
+  ///
+  /// The iterator does not borrow the `SteadyVec`, and yields items in the
+  /// same order as [`segments`](Self::segments) &
+  /// [`segments_mut`](Self::segments_mut), so it can be zipped with either.
+  pub fn segment_ranges(&self) -> SegmentRanges {
+    SegmentRanges::new(self.len)
+  }
+
+  /// Removes the elements in `range`, returning them as an iterator
+  ///
+  /// The elements after the range are shifted down to fill the gap once, when
+  /// the iterator is dropped. If the iterator is dropped before it is fully
+  /// consumed, the remaining elements in the range are dropped.
+  ///
+  /// If the iterator is leaked (e.g. with `mem::forget`), the `SteadyVec` is
+  /// left truncated to the start of the range.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the start of the range is greater than the end, or if the end
+  /// is greater than the length.
+  pub fn drain(&mut self, range: impl RangeBounds<usize>) -> Drain<E> {
+    let range = resolve_range(range, self.len);
+    Drain::new(self, range)
+  }
+
+  /// Removes and drops the elements in `range`, shifting all following
+  /// elements to the left
+  ///
+  /// The removed elements are dropped in place one subarray run at a time,
+  /// then the following elements are shifted down in a single pass. If a
+  /// destructor panics, the following elements are still shifted down.
+  ///
+  /// O(n) time complexity.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the start of the range is greater than the end, or if the end
+  /// is greater than the length.
+  pub fn remove_range(&mut self, range: impl RangeBounds<usize>) {
+    // dropping the `Drain` without consuming it drops the elements in place,
+    // then shifts the tail
+    drop(self.drain(range));
+  }
+
+  /// Replaces the elements in `range` with the elements from `replace_with`,
+  /// returning the removed elements as an iterator
+  ///
+  /// `replace_with` does not need to be the same length as `range`. The
+  /// replacement happens when the iterator is dropped, even if it was not
+  /// fully consumed.
+  ///
+  /// The elements after the range are moved at most once if the lower bound
+  /// of `replace_with`'s `size_hint` is exact.
+  ///
+  /// # Panics
+  ///
+  /// - Panics if the start of the range is greater than the end, or if the end
+  ///   is greater than the length.
+  /// - Panics if the new capacity would exceed [`Self::MAX_CAPACITY`].
+  pub fn splice<I>(
+    &mut self,
+    range: impl RangeBounds<usize>,
+    replace_with: I,
+  ) -> Splice<I::IntoIter>
+  where
+    I: IntoIterator<Item = E>,
+  {
+    Splice::new(self.drain(range), replace_with.into_iter())
+  }
+
+  /// Retains only the elements for which the predicate returns `true`
+  ///
+  /// Removes every element `e` for which `f(&e)` returns `false`. The retained
+  /// elements keep their original order. The elements are visited exactly
+  /// once, in order, and the `SteadyVec` is compacted in a single pass.
+  ///
+  /// O(n) time complexity.
+  pub fn retain(&mut self, mut f: impl FnMut(&E) -> bool) {
+    self.retain_mut(|element| f(element))
+  }
+
+  /// Retains only the elements for which the predicate returns `true`,
+  /// passing a mutable reference to each element
+  ///
+  /// O(n) time complexity.
+  pub fn retain_mut(&mut self, mut f: impl FnMut(&mut E) -> bool) {
+    // safety:
+    // - Similarly to `Vec::retain`, the length is set to 0 while the elements
+    //   are being processed, so that if the guard is leaked no element is
+    //   dropped twice.
+    let original_len = self.len;
+    self.len = 0;
+
+    let mut guard = BackshiftOnDrop {
+      steady_vec: self,
+      processed: 0,
+      deleted: 0,
+      original_len,
+    };
+    let mut read = RawCursor::new(0);
+    let mut write = RawCursor::new(0);
+
+    while guard.processed != original_len {
+      // safety:
+      // - `processed < original_len`, so the subarray exists & the element is
+      //   initialised.
+      // - `write` trails `read` by `deleted`, and points to a hole whenever
+      //   `deleted > 0`.
+      unsafe {
+        let current = read.ptr(guard.steady_vec);
+        if !f(&mut *current) {
+          // increment first, so that if `drop_in_place` panics the element is
+          // not dropped again by the guard.
+          guard.processed += 1;
+          guard.deleted += 1;
+          ptr::drop_in_place(current);
+        } else {
+          if guard.deleted > 0 {
+            let hole = write.ptr(guard.steady_vec);
+            ptr::copy_nonoverlapping(current, hole, 1);
+          }
+          guard.processed += 1;
+          write.advance();
+        }
+      }
+      read.advance();
+    }
+  }
+
+  /// Creates an iterator which uses a closure to determine if an element in
+  /// `range` should be removed
+  ///
+  /// If the closure returns `true`, the element is removed from the
+  /// `SteadyVec` and yielded. Otherwise the element remains, and is shifted
+  /// down over any removed elements. The elements are visited lazily and in
+  /// order, as the iterator advances.
+  ///
+  /// If the iterator is dropped before it is fully consumed, the remaining
+  /// elements are retained. If the iterator is leaked (e.g. with
+  /// `mem::forget`), the `SteadyVec` is left empty.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the start of the range is greater than the end, or if the end
+  /// is greater than the length.
+  pub fn extract_if<F>(
+    &mut self,
+    range: impl RangeBounds<usize>,
+    filter: F,
+  ) -> ExtractIf<E, F>
+  where
+    F: FnMut(&mut E) -> bool,
+  {
+    let range = resolve_range(range, self.len);
+    ExtractIf::new(self, range, filter)
+  }
+
+  /// Removes consecutive repeated elements
+  ///
+  /// If the `SteadyVec` is sorted, this removes all duplicates.
+  ///
+  /// O(n) time complexity.
+  pub fn dedup(&mut self)
+  where
+    E: PartialEq,
+  {
+    self.dedup_by(|a, b| a == b)
+  }
+
+  /// Removes all but the first of consecutive elements that resolve to the
+  /// same key
+  ///
+  /// O(n) time complexity.
+  pub fn dedup_by_key<K>(&mut self, mut key: impl FnMut(&mut E) -> K)
+  where
+    K: PartialEq,
+  {
+    self.dedup_by(|a, b| key(a) == key(b))
+  }
+
+  /// Removes all but the first of consecutive elements satisfying the given
+  /// equality relation
+  ///
+  /// `same_bucket` is passed references to two elements, `(a, b)`, where `b`
+  /// is the element before `a` that was kept. If it returns `true`, then `a`
+  /// is removed.
+  ///
+  /// O(n) time complexity.
+  pub fn dedup_by(
+    &mut self,
+    mut same_bucket: impl FnMut(&mut E, &mut E) -> bool,
+  ) {
+    if self.len <= 1 {
+      return;
+    }
+
+    // safety: see `retain_mut`
+    let original_len = self.len;
+    self.len = 0;
+
+    let mut guard = BackshiftOnDrop {
+      steady_vec: self,
+      processed: 1,
+      deleted: 0,
+      original_len,
+    };
+    let mut read = RawCursor::new(1);
+    let mut write = RawCursor::new(1);
+    // safety: the length is at least 1, so the first element exists.
+    let mut previous: *mut E = unsafe { guard.steady_vec.element_ptr(0) };
+
+    while guard.processed != original_len {
+      // safety:
+      // - `processed < original_len`, so the subarray exists & the element is
+      //   initialised.
+      // - `previous` points to the last kept element, which is always in the
+      //   range `0..processed - deleted`, so it doesn't alias `current`.
+      // - `write` trails `read` by `deleted`, and points to a hole whenever
+      //   `deleted > 0`.
+      unsafe {
+        let current = read.ptr(guard.steady_vec);
+        if same_bucket(&mut *current, &mut *previous) {
+          // increment first, so that if `drop_in_place` panics the element is
+          // not dropped again by the guard.
+          guard.processed += 1;
+          guard.deleted += 1;
+          ptr::drop_in_place(current);
+        } else {
+          let hole = write.ptr(guard.steady_vec);
+          if guard.deleted > 0 {
+            ptr::copy_nonoverlapping(current, hole, 1);
+          }
+          previous = hole;
+          guard.processed += 1;
+          write.advance();
+        }
+      }
+      read.advance();
+    }
+  }
+
+  /// Clones and appends all the elements in a slice
+  ///
+  /// Capacity is reserved up front, then the elements are cloned directly into
+  /// each destination subarray, a run of contiguous elements at a time.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the new length would exceed [`Self::MAX_CAPACITY`].
+  pub fn extend_from_slice(&mut self, other: &[E])
+  where
+    E: Clone,
+  {
+    self.reserve(other.len());
+    // safety: capacity was reserved, and `other` cannot overlap `self`
+    // since `self` is borrowed mutably.
+    unsafe { self.extend_from_slice_unchecked(other) };
+  }
+
+  /// Clones the elements in `range` and appends them to the end
+  ///
+  /// # Panics
+  ///
+  /// - Panics if the start of the range is greater than the end, or if the end
+  ///   is greater than the length.
+  /// - Panics if the new length would exceed [`Self::MAX_CAPACITY`].
+  pub fn extend_from_within(&mut self, range: impl RangeBounds<usize>)
+  where
+    E: Clone,
+  {
+    let range = resolve_range(range, self.len);
+    self.reserve(range.len());
+
+    for run in subarray_runs(range) {
+      // safety:
+      // - the range is in bounds, so the elements of the run are initialised.
+      // - the source elements are below the old length, so they do not
+      //   overlap the uninitialised slots being written, and are never moved
+      //   or dropped while they are borrowed.
+      unsafe {
+        let subarray =
+          self.subarrays[run.subarray_n].as_ref().unwrap_unchecked();
+        let source: &[E] = ::core::slice::from_raw_parts(
+          subarray.element_ptr(run.first_element),
+          run.len(),
+        );
+        self.extend_from_slice_unchecked(source);
+      }
+    }
+  }
+
+  /// Clones and appends all the elements in a slice, without reserving
+  ///
+  /// The length is bumped after every element, so if a `Clone` implementation
+  /// panics the elements already cloned are still dropped with the vec.
+  ///
+  /// # Safety
+  ///
+  /// - the capacity must be at least `self.len + other.len()`.
+  /// - `other` must not overlap the slots from `self.len..`.
+  unsafe fn extend_from_slice_unchecked(&mut self, mut other: &[E])
+  where
+    E: Clone,
+  {
+    for run in subarray_runs(self.len..self.len + other.len()) {
+      // safety:
+      // - the caller promises the capacity, so the subarray exists.
+      // - the run starts at `self.len`, so the slots are uninitialised.
+      let destination = unsafe {
+        let subarray =
+          self.subarrays[run.subarray_n].as_ref().unwrap_unchecked();
+        subarray.element_ptr(run.first_element)
+      };
+      let (source, rest) = other.split_at(run.len());
+
+      for (i, element) in source.iter().enumerate() {
+        // safety: `i < run.len()`, so the slot is inside the run.
+        unsafe { destination.add(i).write(element.clone()) };
+        self.len += 1;
+      }
+
+      other = rest;
+    }
+  }
 
-  // pub fn retain_mut(&mut self, f: impl FnMut(&mut E) -> bool) {
-  //   todo!()
-  // }
+  /// Appends all the elements of an iterator
+  ///
+  /// Space for the lower bound of the iterator's `size_hint` is reserved up
+  /// front, then the elements are written straight into the uninitialised
+  /// slots of each subarray until the iterator runs out. The capacity is only
+  /// checked again when a subarray is filled.
+  fn extend_from_iter(&mut self, mut iter: impl Iterator<Item = E>) {
+    while let Some(element) = iter.next() {
+      let (lower, _) = iter.size_hint();
+      self.reserve(lower.saturating_add(1));
+
+      let index_metadata = index_metadata(self.len);
+      // safety:
+      // - `reserve` ensures the subarray for `self.len` exists.
+      // - the slots from `self.len` to the end of the subarray are
+      //   uninitialised.
+      let destination = unsafe {
+        let subarray = self.subarrays[index_metadata.subarray_n]
+          .as_mut()
+          .unwrap_unchecked();
+        subarray.as_uninit_slice_mut(
+          index_metadata.element,
+          subarray_capacity(index_metadata.subarray_n) - 1,
+        )
+      };
+
+      // `len` is incremented after each element is written, so if the
+      // iterator panics the written elements are kept.
+      let mut slots = destination.iter_mut();
+      // safety: the slice contains at least the slot for `self.len`.
+      unsafe { slots.next().unwrap_unchecked() }.write(element);
+      self.len += 1;
+      for slot in slots {
+        let Some(element) = iter.next() else {
+          return;
+        };
+        slot.write(element);
+        self.len += 1;
+      }
+    }
+  }
 
   /// Resizes the `SteadyVec` in place
   ///
@@ -702,6 +1834,38 @@ impl<E> SteadyVec<E> {
   }
 }
 
+/// A guard used while compacting a `SteadyVec` in place
+///
+/// While compacting, the length of the `SteadyVec` is set to 0. The elements
+/// from `0..processed - deleted` have been kept, and the elements from
+/// `processed..original_len` have not been visited yet.
+///
+/// When dropped, shifts the unprocessed elements down over the holes left by
+/// deleted elements, and restores the length; even if a predicate or a
+/// destructor panics.
+struct BackshiftOnDrop<'s, E> {
+  steady_vec: &'s mut SteadyVec<E>,
+  processed: usize,
+  deleted: usize,
+  original_len: usize,
+}
+
+impl<'s, E> Drop for BackshiftOnDrop<'s, E> {
+  fn drop(&mut self) {
+    // safety:
+    // - the elements from `processed..original_len` are initialised, and
+    //   the holes left by deleted elements are directly below them.
+    unsafe {
+      self.steady_vec.copy_within_unchecked(
+        self.processed,
+        self.processed - self.deleted,
+        self.original_len - self.processed,
+      );
+    }
+    self.steady_vec.len = self.original_len - self.deleted;
+  }
+}
+
 impl<E> Index<usize> for SteadyVec<E> {
   type Output = E;
 
@@ -764,18 +1928,23 @@ impl<E> IntoIterator for Box<SteadyVec<E>> {
 
 impl<E> Extend<E> for SteadyVec<E> {
   fn extend<I: IntoIterator<Item = E>>(&mut self, iter: I) {
-    for item in iter {
-      self.push(item)
-    }
+    self.extend_from_iter(iter.into_iter())
+  }
+}
+
+impl<'a, E> Extend<&'a E> for SteadyVec<E>
+where
+  E: Copy + 'a,
+{
+  fn extend<I: IntoIterator<Item = &'a E>>(&mut self, iter: I) {
+    self.extend_from_iter(iter.into_iter().copied())
   }
 }
 
 impl<E> FromIterator<E> for SteadyVec<E> {
   fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self {
     let mut steady_vec = SteadyVec::new();
-    for item in iter {
-      steady_vec.push(item)
-    }
+    steady_vec.extend_from_iter(iter.into_iter());
     steady_vec
   }
 }
@@ -783,13 +1952,17 @@ impl<E> FromIterator<E> for SteadyVec<E> {
 impl<E> FromIterator<E> for Box<SteadyVec<E>> {
   fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self {
     let mut steady_vec = SteadyVec::new_boxed();
-    for item in iter {
-      steady_vec.push(item)
-    }
+    steady_vec.extend_from_iter(iter.into_iter());
     steady_vec
   }
 }
 
+impl<E> Default for SteadyVec<E> {
+  fn default() -> Self {
+    SteadyVec::new()
+  }
+}
+
 impl<E> Clone for SteadyVec<E>
 where
   E: Clone,
@@ -867,6 +2040,15 @@ where
   }
 }
 
+impl<E> fmt::Debug for SteadyVec<E>
+where
+  E: fmt::Debug,
+{
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_list().entries(self.iter()).finish()
+  }
+}
+
 impl<E> Drop for SteadyVec<E> {
   fn drop(&mut self) {
     // drop-in-place all the elements
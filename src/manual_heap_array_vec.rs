@@ -10,6 +10,12 @@ pub(crate) struct ManualHeapArrayVec<E> {
   data: NonNull<MaybeUninit<E>>,
 }
 
+// safety: `ManualHeapArrayVec` owns its elements like a `Box<[E]>` does, so it
+// may be sent or shared between threads whenever `E` may be. `SteadyVec` and
+// the types borrowing it derive their `Send` & `Sync` impls from these.
+unsafe impl<E: Send> Send for ManualHeapArrayVec<E> {}
+unsafe impl<E: Sync> Sync for ManualHeapArrayVec<E> {}
+
 impl<E> ManualHeapArrayVec<E> {
   pub(crate) const OPTION_NONE: Option<Self> = None;
 
@@ -66,6 +72,20 @@ impl<E> ManualHeapArrayVec<E> {
     }
   }
 
+  /// Get a raw pointer to the element at `element_index`
+  ///
+  /// The pointer is derived from the allocation itself rather than from
+  /// `self`, so pointers to distinct elements may be used to create disjoint
+  /// mutable references.
+  ///
+  /// # Safety
+  ///
+  /// - `element_index` must be less than or equal to the capacity.
+  #[inline]
+  pub(crate) unsafe fn element_ptr(&self, element_index: usize) -> *mut E {
+    unsafe { self.data.add(element_index).as_ptr().cast::<E>() }
+  }
+
   /// Get the subslice from `start..=end`
   ///
   /// if `end - start == -1`, then the slice is empty.
@@ -0,0 +1,105 @@
+use {
+  super::{index_to_subarray_n, subarray_index_range, SteadyVec},
+  ::core::ptr,
+};
+
+/// A raw pointer to an element of a `SteadyVec`, which can be moved one index
+/// at a time
+///
+/// The subarray metadata is only recomputed when the cursor crosses into a
+/// different subarray, so walking a range of elements costs O(1) per step
+/// instead of an `index_metadata` call per element.
+///
+/// The cursor does not borrow the `SteadyVec`; the caller must supply it when
+/// the pointer is needed, and is responsible for the validity of the elements.
+pub(crate) struct RawCursor<E> {
+  index: usize,
+  /// pointer to the element at `index`, only valid while `index` is in
+  /// `run_start..run_end`
+  ptr: *mut E,
+  /// the first index of the subarray that `ptr` points into
+  run_start: usize,
+  /// the first index after the subarray that `ptr` points into
+  run_end: usize,
+}
+
+impl<E> RawCursor<E> {
+  #[inline]
+  pub(crate) fn new(index: usize) -> Self {
+    RawCursor {
+      index,
+      ptr: ptr::null_mut(),
+      run_start: index,
+      run_end: index,
+    }
+  }
+
+  /// The index the cursor points to
+  #[inline]
+  pub(crate) fn index(&self) -> usize {
+    self.index
+  }
+
+  /// Get a raw pointer to the element at the cursor's index
+  ///
+  /// # Safety
+  ///
+  /// - the subarray containing the cursor's index must exist in `steady_vec`.
+  /// - `steady_vec` must be the same `SteadyVec` on every call.
+  #[inline]
+  pub(crate) unsafe fn ptr(&mut self, steady_vec: &SteadyVec<E>) -> *mut E {
+    if self.index < self.run_start || self.index >= self.run_end {
+      let (first_index, last_index) =
+        subarray_index_range(index_to_subarray_n(self.index));
+      // safety: the caller promises the subarray exists.
+      self.ptr = unsafe { steady_vec.element_ptr(self.index) };
+      self.run_start = first_index;
+      self.run_end = last_index + 1;
+    }
+    self.ptr
+  }
+
+  /// Move the cursor to the next index
+  #[inline]
+  pub(crate) fn advance(&mut self) {
+    self.index += 1;
+    // `ptr` tracks `index` even outside of the run, so that it is still
+    // correct if the cursor moves back into the run. It is only dereferenced
+    // while `index` is within the run.
+    self.ptr = self.ptr.wrapping_add(1);
+  }
+
+  /// Move the cursor to the previous index
+  ///
+  /// The cursor may be moved below index 0, as long as its pointer is not
+  /// used afterwards.
+  #[inline]
+  pub(crate) fn retreat(&mut self) {
+    self.index = self.index.wrapping_sub(1);
+    self.ptr = self.ptr.wrapping_sub(1);
+  }
+
+  /// Move the cursor to `index`
+  ///
+  /// The cached pointer is reused if `index` is within the same subarray.
+  #[inline]
+  pub(crate) fn seek(&mut self, index: usize) {
+    self.ptr = if index >= self.index {
+      self.ptr.wrapping_add(index - self.index)
+    } else {
+      self.ptr.wrapping_sub(self.index - index)
+    };
+    self.index = index;
+  }
+
+  /// Get the raw pointer last returned by [`ptr`](Self::ptr), adjusted for any
+  /// movement since
+  ///
+  /// Unlike `ptr`, this never updates the cached subarray, so the pointer is
+  /// only valid if `ptr` has been called since the cursor last moved into a
+  /// different subarray.
+  #[inline]
+  pub(crate) fn cached_ptr(&self) -> *mut E {
+    self.ptr
+  }
+}
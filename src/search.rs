@@ -0,0 +1,154 @@
+//! Searching
+
+use {
+  super::{initialised_subarray_count, subarray_index_range, SteadyVec},
+  ::core::cmp::Ordering,
+};
+
+impl<E> SteadyVec<E> {
+  /// Returns `true` if the `SteadyVec` contains an element equal to `x`
+  ///
+  /// Each segment is searched as a contiguous slice.
+  pub fn contains(&self, x: &E) -> bool
+  where
+    E: PartialEq,
+  {
+    self.segments().any(|segment| segment.contains(x))
+  }
+
+  /// Returns `true` if `needle` is a prefix of the `SteadyVec`
+  pub fn starts_with(&self, needle: &[E]) -> bool
+  where
+    E: PartialEq,
+  {
+    if needle.len() > self.len {
+      return false;
+    }
+
+    let mut needle = needle;
+    // safety: the range is in bounds.
+    let mut runs = unsafe { self.run_slices(0..needle.len()) };
+    runs.all(|run| {
+      let (head, rest) = needle.split_at(run.len());
+      needle = rest;
+      run == head
+    })
+  }
+
+  /// Returns `true` if `needle` is a suffix of the `SteadyVec`
+  pub fn ends_with(&self, needle: &[E]) -> bool
+  where
+    E: PartialEq,
+  {
+    if needle.len() > self.len {
+      return false;
+    }
+
+    let mut needle = needle;
+    // safety: the range is in bounds.
+    let runs = unsafe { self.run_slices(self.len - needle.len()..self.len) };
+    runs.rev().all(|run| {
+      let (rest, tail) = needle.split_at(needle.len() - run.len());
+      needle = rest;
+      run == tail
+    })
+  }
+
+  /// Binary searches a sorted `SteadyVec` for the given element
+  ///
+  /// If the element is found, returns `Ok` with its index. If there are
+  /// multiple matches, any one of them may be returned. If the element is not
+  /// found, returns `Err` with the index where it could be inserted while
+  /// maintaining the sorted order.
+  ///
+  /// The segment that may contain the element is found first by comparing
+  /// against the first element of each segment, then the search continues
+  /// within that single contiguous segment.
+  pub fn binary_search(&self, x: &E) -> Result<usize, usize>
+  where
+    E: Ord,
+  {
+    self.binary_search_by(|element| element.cmp(x))
+  }
+
+  /// Binary searches a sorted `SteadyVec` with a comparator function
+  ///
+  /// The comparator should return whether its argument is `Less`, `Equal` or
+  /// `Greater` than the desired target, and must be consistent with the sort
+  /// order of the `SteadyVec`. See [`binary_search`](Self::binary_search).
+  pub fn binary_search_by(
+    &self,
+    mut f: impl FnMut(&E) -> Ordering,
+  ) -> Result<usize, usize> {
+    let Some(subarray_n) =
+      self.find_segment(|element| f(element) != Ordering::Greater)
+    else {
+      return Err(0);
+    };
+
+    let (first_index, _) = subarray_index_range(subarray_n);
+    // safety: `find_segment` returns an initialised subarray.
+    let segment = unsafe { self.segment_slice(subarray_n) };
+    segment
+      .binary_search_by(f)
+      .map(|index| first_index + index)
+      .map_err(|index| first_index + index)
+  }
+
+  /// Binary searches a sorted `SteadyVec` with a key extraction function
+  ///
+  /// The `SteadyVec` must be sorted by the key. See
+  /// [`binary_search`](Self::binary_search).
+  pub fn binary_search_by_key<K>(
+    &self,
+    key: &K,
+    mut f: impl FnMut(&E) -> K,
+  ) -> Result<usize, usize>
+  where
+    K: Ord,
+  {
+    self.binary_search_by(|element| f(element).cmp(key))
+  }
+
+  /// Returns the index of the partition point according to the given
+  /// predicate
+  ///
+  /// The `SteadyVec` must be partitioned, such that all the elements for which
+  /// the predicate returns `true` are before all the elements for which it
+  /// returns `false`. Returns the index of the first element for which the
+  /// predicate returns `false`, or the length if there is none.
+  pub fn partition_point(&self, mut pred: impl FnMut(&E) -> bool) -> usize {
+    let Some(subarray_n) = self.find_segment(&mut pred) else {
+      return 0;
+    };
+
+    let (first_index, _) = subarray_index_range(subarray_n);
+    // safety: `find_segment` returns an initialised subarray.
+    let segment = unsafe { self.segment_slice(subarray_n) };
+    first_index + segment.partition_point(pred)
+  }
+
+  /// Returns the last initialised subarray whose first element satisfies the
+  /// predicate, or `None` if there is no such subarray
+  ///
+  /// The `SteadyVec` must be partitioned by the predicate.
+  fn find_segment(&self, mut pred: impl FnMut(&E) -> bool) -> Option<usize> {
+    // binary search for the first subarray that does not satisfy `pred`
+    let mut low = 0;
+    let mut high = initialised_subarray_count(self.len);
+    while low < high {
+      let mid = low + (high - low) / 2;
+      let (first_index, _) = subarray_index_range(mid);
+      // safety: `mid` is an initialised subarray, so its first element is
+      // initialised.
+      let first = unsafe { &*self.element_ptr(first_index) };
+      if pred(first) {
+        low = mid + 1;
+      } else {
+        high = mid;
+      }
+    }
+
+    low.checked_sub(1)
+  }
+}
@@ -0,0 +1,426 @@
+//! Borrowed views over a range of a `SteadyVec`
+
+use {
+  super::{
+    iter::{SteadyVecIter, SteadyVecIterMut},
+    resolve_range, SteadyVec,
+  },
+  ::core::{
+    marker::PhantomData,
+    ops::{Index, IndexMut, Range, RangeBounds},
+  },
+};
+
+/// A borrowed view of a range of elements in a `SteadyVec`
+///
+/// This is the `SteadyVec` analogue of `&[E]`. Since the elements of a
+/// `SteadyVec` are not contiguous in memory, the view stores a reference to
+/// the `SteadyVec` along with the range of indices it covers. Created using
+/// [`SteadyVec::slice`].
+///
+/// Indices passed to the methods of a `SteadySlice` are relative to the start
+/// of the view.
+pub struct SteadySlice<'s, E: 's> {
+  steady_vec: &'s SteadyVec<E>,
+  // the first index in the view
+  start: usize,
+  // the last index in the view + 1 (exclusive)
+  end: usize,
+}
+
+impl<'s, E> SteadySlice<'s, E> {
+  /// `range` must be in bounds of `steady_vec.len`.
+  pub(crate) fn new(
+    steady_vec: &'s SteadyVec<E>,
+    range: Range<usize>,
+  ) -> Self {
+    SteadySlice {
+      steady_vec,
+      start: range.start,
+      end: range.end,
+    }
+  }
+
+  /// Returns the number of elements in the view
+  pub fn len(&self) -> usize {
+    self.end - self.start
+  }
+
+  /// Returns `true` if the view contains no elements
+  pub fn is_empty(&self) -> bool {
+    self.start >= self.end
+  }
+
+  /// The range of indices (into the underlying `SteadyVec`) covered by the
+  /// view
+  pub fn range(&self) -> Range<usize> {
+    self.start..self.end
+  }
+
+  /// Get the element at the index
+  pub fn get(&self, index: usize) -> Option<&'s E> {
+    if index >= self.len() {
+      return None;
+    }
+
+    self.steady_vec.get(self.start + index)
+  }
+
+  /// Get the first element, or `None` if empty
+  pub fn first(&self) -> Option<&'s E> {
+    self.get(0)
+  }
+
+  /// Get the last element, or `None` if empty
+  pub fn last(&self) -> Option<&'s E> {
+    self.get(self.len().checked_sub(1)?)
+  }
+
+  /// Returns an iterator over each element of the view
+  pub fn iter(&self) -> SteadyVecIter<'s, E> {
+    SteadyVecIter::with_range(self.steady_vec, self.start..self.end)
+  }
+
+  /// Returns a view of the elements in `range`, relative to this view
+  ///
+  /// # Panics
+  ///
+  /// Panics if the start of the range is greater than the end, or if the end
+  /// is greater than the length.
+  pub fn slice(&self, range: impl RangeBounds<usize>) -> SteadySlice<'s, E> {
+    let range = resolve_range(range, self.len());
+    SteadySlice::new(
+      self.steady_vec,
+      (self.start + range.start)..(self.start + range.end),
+    )
+  }
+
+  /// Divides the view into two at `mid`
+  ///
+  /// The first view contains the indices `0..mid`, and the second contains
+  /// `mid..len`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `mid` is greater than the length.
+  pub fn split_at(
+    &self,
+    mid: usize,
+  ) -> (SteadySlice<'s, E>, SteadySlice<'s, E>) {
+    (self.slice(..mid), self.slice(mid..))
+  }
+}
+
+impl<'s, E> Clone for SteadySlice<'s, E> {
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+
+impl<'s, E> Copy for SteadySlice<'s, E> {}
+
+impl<'s, E> Index<usize> for SteadySlice<'s, E> {
+  type Output = E;
+
+  fn index(&self, index: usize) -> &Self::Output {
+    self.get(index).expect("index is out of bounds")
+  }
+}
+
+impl<'s, E> IntoIterator for SteadySlice<'s, E> {
+  type Item = &'s E;
+  type IntoIter = SteadyVecIter<'s, E>;
+
+  /// Returns an iterator over each element of the view
+  fn into_iter(self) -> SteadyVecIter<'s, E> {
+    self.iter()
+  }
+}
+
+impl<'a, 's, E> IntoIterator for &'a SteadySlice<'s, E> {
+  type Item = &'s E;
+  type IntoIter = SteadyVecIter<'s, E>;
+
+  /// Returns an iterator over each element of the view
+  fn into_iter(self) -> SteadyVecIter<'s, E> {
+    self.iter()
+  }
+}
+
+/// A mutably borrowed view of a range of elements in a `SteadyVec`
+///
+/// This is the `SteadyVec` analogue of `&mut [E]`. Created using
+/// [`SteadyVec::slice_mut`].
+///
+/// Indices passed to the methods of a `SteadySliceMut` are relative to the
+/// start of the view.
+//
+// # Safety
+//
+// The `SteadyVec` is exclusively borrowed for `'s`, but we only hold a shared
+// reference to it. This means several views over disjoint ranges may exist at
+// once without aliasing the `SteadyVec` (or its `subarrays`) mutably. Mutable
+// references to elements are derived from the subarray allocations directly.
+// `SteadyVecIterMut` & `SegmentsMut` rely on the same reasoning.
+//
+// A `SteadySliceMut` has exclusive access to the elements in its `range`.
+pub struct SteadySliceMut<'s, E: 's> {
+  steady_vec: &'s SteadyVec<E>,
+  range: Range<usize>,
+  _lifetime: PhantomData<&'s mut E>,
+}
+
+// safety: `SteadySliceMut` behaves like a `&mut [E]`, even though it only
+// holds a shared reference to the `SteadyVec`.
+unsafe impl<'s, E: Send> Send for SteadySliceMut<'s, E> {}
+unsafe impl<'s, E: Sync> Sync for SteadySliceMut<'s, E> {}
+
+impl<'s, E> SteadySliceMut<'s, E> {
+  /// `range` must be in bounds of `steady_vec.len`.
+  pub(crate) fn new(
+    steady_vec: &'s mut SteadyVec<E>,
+    range: Range<usize>,
+  ) -> Self {
+    // safety: the mutable borrow of `steady_vec` rules out other references.
+    unsafe { Self::from_raw_parts(steady_vec, range) }
+  }
+
+  /// # Safety
+  ///
+  /// - `range` must be in bounds of `steady_vec.len`.
+  /// - no other references to the elements in `range` may exist for `'s`.
+  pub(crate) unsafe fn from_raw_parts(
+    steady_vec: &'s SteadyVec<E>,
+    range: Range<usize>,
+  ) -> Self {
+    SteadySliceMut {
+      steady_vec,
+      range,
+      _lifetime: PhantomData,
+    }
+  }
+
+  /// Returns the number of elements in the view
+  pub fn len(&self) -> usize {
+    self.range.len()
+  }
+
+  /// Returns `true` if the view contains no elements
+  pub fn is_empty(&self) -> bool {
+    self.range.is_empty()
+  }
+
+  /// The range of indices (into the underlying `SteadyVec`) covered by the
+  /// view
+  pub fn range(&self) -> Range<usize> {
+    self.range.clone()
+  }
+
+  /// Returns an immutable view of the same elements
+  pub fn as_slice(&self) -> SteadySlice<E> {
+    SteadySlice::new(self.steady_vec, self.range.clone())
+  }
+
+  /// Get the element at the index
+  pub fn get(&self, index: usize) -> Option<&E> {
+    self.as_slice().get(index)
+  }
+
+  /// Mutably get the element at the index
+  pub fn get_mut(&mut self, index: usize) -> Option<&mut E> {
+    if index >= self.len() {
+      return None;
+    }
+
+    // safety:
+    // - the index is in bounds of the view, so the element is initialised.
+    // - the view has exclusive access to the element, and `self` is borrowed
+    //   mutably for the lifetime of the returned reference.
+    let element =
+      unsafe { &mut *self.steady_vec.element_ptr(self.range.start + index) };
+
+    Some(element)
+  }
+
+  /// Get the first element, or `None` if empty
+  pub fn first(&self) -> Option<&E> {
+    self.get(0)
+  }
+
+  /// Get the last element, or `None` if empty
+  pub fn last(&self) -> Option<&E> {
+    self.get(self.len().checked_sub(1)?)
+  }
+
+  /// Mutably get the first element, or `None` if empty
+  pub fn first_mut(&mut self) -> Option<&mut E> {
+    self.get_mut(0)
+  }
+
+  /// Mutably get the last element, or `None` if empty
+  pub fn last_mut(&mut self) -> Option<&mut E> {
+    self.get_mut(self.len().checked_sub(1)?)
+  }
+
+  /// Returns an iterator over each element of the view
+  pub fn iter(&self) -> SteadyVecIter<E> {
+    SteadyVecIter::with_range(self.steady_vec, self.range.clone())
+  }
+
+  /// Returns an iterator that allows modifying each element of the view
+  pub fn iter_mut(&mut self) -> SteadyVecIterMut<E> {
+    // safety: the view has exclusive access to the elements in its range, and
+    // `self` is borrowed mutably for the lifetime of the iterator.
+    unsafe {
+      SteadyVecIterMut::with_range(self.steady_vec, self.range.clone())
+    }
+  }
+
+  /// Returns a view of the elements in `range`, relative to this view
+  ///
+  /// # Panics
+  ///
+  /// Panics if the start of the range is greater than the end, or if the end
+  /// is greater than the length.
+  pub fn slice(&self, range: impl RangeBounds<usize>) -> SteadySlice<E> {
+    self.as_slice().slice(range)
+  }
+
+  /// Returns a mutable view of the elements in `range`, relative to this view
+  ///
+  /// # Panics
+  ///
+  /// Panics if the start of the range is greater than the end, or if the end
+  /// is greater than the length.
+  pub fn slice_mut(
+    &mut self,
+    range: impl RangeBounds<usize>,
+  ) -> SteadySliceMut<E> {
+    let range = resolve_range(range, self.len());
+    let start = self.range.start;
+    // safety: the new range is a subset of this view's range, and `self` is
+    // borrowed mutably for the lifetime of the new view.
+    unsafe {
+      SteadySliceMut::from_raw_parts(
+        self.steady_vec,
+        (start + range.start)..(start + range.end),
+      )
+    }
+  }
+
+  /// Divides the view into two immutable views at `mid`
+  ///
+  /// # Panics
+  ///
+  /// Panics if `mid` is greater than the length.
+  pub fn split_at(&self, mid: usize) -> (SteadySlice<E>, SteadySlice<E>) {
+    self.as_slice().split_at(mid)
+  }
+
+  /// Divides the view into two mutable views at `mid`
+  ///
+  /// The first view contains the indices `0..mid`, and the second contains
+  /// `mid..len`. Both views may be used (and split further) at the same time.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `mid` is greater than the length.
+  pub fn split_at_mut(
+    &mut self,
+    mid: usize,
+  ) -> (SteadySliceMut<E>, SteadySliceMut<E>) {
+    // safety: `self` is borrowed mutably for the lifetime of both views.
+    unsafe { self.split_at_mut_unchecked_lifetime(mid) }
+  }
+
+  /// Divides the view into two mutable views at `mid`, consuming it
+  ///
+  /// This is the same as [`split_at_mut`](Self::split_at_mut), except that the
+  /// new views keep the lifetime of the original borrow of the `SteadyVec`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `mid` is greater than the length.
+  pub fn into_split_at_mut(
+    self,
+    mid: usize,
+  ) -> (SteadySliceMut<'s, E>, SteadySliceMut<'s, E>) {
+    // safety: `self` is consumed, so only the new views have access to its
+    // elements for `'s`.
+    unsafe { self.split_at_mut_unchecked_lifetime(mid) }
+  }
+
+  /// # Safety
+  ///
+  /// - the caller must ensure no other views of the elements in `self` are
+  ///   used for `'a`.
+  unsafe fn split_at_mut_unchecked_lifetime<'a>(
+    &self,
+    mid: usize,
+  ) -> (SteadySliceMut<'a, E>, SteadySliceMut<'a, E>)
+  where
+    's: 'a,
+  {
+    if mid > self.len() {
+      panic!(
+        "mid is out of bounds, mid: {mid}, len: {len}",
+        len = self.len()
+      );
+    }
+
+    let mid = self.range.start + mid;
+    // safety: the two ranges are disjoint subsets of this view's range.
+    unsafe {
+      (
+        SteadySliceMut::from_raw_parts(self.steady_vec, self.range.start..mid),
+        SteadySliceMut::from_raw_parts(self.steady_vec, mid..self.range.end),
+      )
+    }
+  }
+}
+
+impl<'s, E> Index<usize> for SteadySliceMut<'s, E> {
+  type Output = E;
+
+  fn index(&self, index: usize) -> &Self::Output {
+    self.get(index).expect("index is out of bounds")
+  }
+}
+
+impl<'s, E> IndexMut<usize> for SteadySliceMut<'s, E> {
+  fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+    self.get_mut(index).expect("index is out of bounds")
+  }
+}
+
+impl<'s, E> IntoIterator for SteadySliceMut<'s, E> {
+  type Item = &'s mut E;
+  type IntoIter = SteadyVecIterMut<'s, E>;
+
+  /// Returns an iterator that allows modifying each element of the view
+  fn into_iter(self) -> SteadyVecIterMut<'s, E> {
+    // safety: the view had exclusive access to the elements in its range for
+    // `'s`, and it is consumed.
+    unsafe { SteadyVecIterMut::with_range(self.steady_vec, self.range) }
+  }
+}
+
+impl<'a, 's, E> IntoIterator for &'a SteadySliceMut<'s, E> {
+  type Item = &'a E;
+  type IntoIter = SteadyVecIter<'a, E>;
+
+  /// Returns an iterator over each element of the view
+  fn into_iter(self) -> SteadyVecIter<'a, E> {
+    self.iter()
+  }
+}
+
+impl<'a, 's, E> IntoIterator for &'a mut SteadySliceMut<'s, E> {
+  type Item = &'a mut E;
+  type IntoIter = SteadyVecIterMut<'a, E>;
+
+  /// Returns an iterator that allows modifying each element of the view
+  fn into_iter(self) -> SteadyVecIterMut<'a, E> {
+    self.iter_mut()
+  }
+}
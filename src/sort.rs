@@ -0,0 +1,709 @@
+//! Sorting
+
+use {
+  super::{
+    index_to_subarray_n, initialised_subarray_count, raw_cursor::RawCursor,
+    slice::SteadySliceMut, subarray_index_range, Segment, SteadyVec,
+  },
+  ::core::{cmp::Ordering, ops::Range, ptr},
+};
+
+impl<E> SteadyVec<E> {
+  /// Sorts the `SteadyVec` in place
+  ///
+  /// This sort is stable (i.e. does not reorder equal elements), and
+  /// O(n * log(n)) worst-case.
+  ///
+  /// Each segment is sorted with [`slice::sort`], and then the sorted segments
+  /// are merged in order. The subarrays are never reallocated; if the
+  /// `SteadyVec` has spare capacity in an unused subarray it is used as
+  /// scratch space for merging, otherwise a temporary buffer up to half the
+  /// length is allocated.
+  ///
+  /// If the comparison panics, every element is still dropped exactly once,
+  /// but the order of the elements is unspecified.
+  pub fn sort(&mut self)
+  where
+    E: Ord,
+  {
+    self.merge_sort(&mut E::cmp)
+  }
+
+  /// Sorts the `SteadyVec` in place with a comparator function
+  ///
+  /// The comparator must define a total ordering. This sort is stable, see
+  /// [`sort`](Self::sort).
+  pub fn sort_by(&mut self, mut compare: impl FnMut(&E, &E) -> Ordering) {
+    self.merge_sort(&mut compare)
+  }
+
+  /// Sorts the `SteadyVec` in place with a key extraction function
+  ///
+  /// The key function is called twice per comparison; for expensive key
+  /// functions [`sort_by_cached_key`](Self::sort_by_cached_key) is likely to
+  /// be faster. This sort is stable, see [`sort`](Self::sort).
+  pub fn sort_by_key<K>(&mut self, mut f: impl FnMut(&E) -> K)
+  where
+    K: Ord,
+  {
+    self.merge_sort(&mut |a: &E, b: &E| f(a).cmp(&f(b)))
+  }
+
+  /// Sorts the `SteadyVec` in place with a key extraction function, calling
+  /// the key function only once per element
+  ///
+  /// The keys are stored in a temporary `Vec` along with the original index of
+  /// each element, which is then used to permute the elements in place with
+  /// one swap per element. This sort is stable.
+  pub fn sort_by_cached_key<K>(&mut self, f: impl FnMut(&E) -> K)
+  where
+    K: Ord,
+  {
+    if self.len < 2 {
+      return;
+    }
+
+    let mut indices: Vec<(K, usize)> = self
+      .iter()
+      .map(f)
+      .enumerate()
+      .map(|(index, key)| (key, index))
+      .collect();
+    // The indices are unique, so an unstable sort produces a stable order.
+    indices.sort_unstable();
+
+    // `i` walks forwards, so its pointer comes from a cursor; the swap
+    // partner is looked up directly.
+    let mut cursor = RawCursor::new(0);
+    for i in 0..self.len {
+      let mut index = indices[i].1;
+      // follow the chain of swaps already made, to find where the element
+      // originally at `index` has moved to.
+      while index < i {
+        index = indices[index].1;
+      }
+      indices[i].1 = index;
+      // safety: both indices are below the length, so the elements are
+      // initialised; `ptr::swap` allows the pointers to be equal.
+      unsafe { ptr::swap(cursor.ptr(self), self.element_ptr(index)) };
+      cursor.advance();
+    }
+  }
+
+  /// Sorts the `SteadyVec` in place, without preserving the order of equal
+  /// elements
+  ///
+  /// This sort is unstable, does not allocate, and is O(n * log(n))
+  /// worst-case.
+  ///
+  /// The current implementation is a quicksort that partitions across
+  /// segments until a range lies within a single segment, at which point it
+  /// defers to [`slice::sort_unstable`]. Ranges with many equal elements are
+  /// handled in linear time, and it falls back to heapsort if partitioning
+  /// goes badly.
+  ///
+  /// If the comparison panics, every element is still dropped exactly once,
+  /// but the order of the elements is unspecified.
+  pub fn sort_unstable(&mut self)
+  where
+    E: Ord,
+  {
+    self.quicksort(&mut E::cmp)
+  }
+
+  /// Sorts the `SteadyVec` in place with a comparator function, without
+  /// preserving the order of equal elements
+  ///
+  /// The comparator must define a total ordering. See
+  /// [`sort_unstable`](Self::sort_unstable).
+  pub fn sort_unstable_by(
+    &mut self,
+    mut compare: impl FnMut(&E, &E) -> Ordering,
+  ) {
+    self.quicksort(&mut compare)
+  }
+
+  /// Sorts the `SteadyVec` in place with a key extraction function, without
+  /// preserving the order of equal elements
+  ///
+  /// See [`sort_unstable`](Self::sort_unstable).
+  pub fn sort_unstable_by_key<K>(&mut self, mut f: impl FnMut(&E) -> K)
+  where
+    K: Ord,
+  {
+    self.quicksort(&mut |a: &E, b: &E| f(a).cmp(&f(b)))
+  }
+
+  /// Reorders the `SteadyVec` such that the element at `index` is at its
+  /// final sorted position
+  ///
+  /// Every element before `index` is less than or equal to it, and every
+  /// element after `index` is greater than or equal to it. Returns mutable
+  /// views of the elements before `index`, the element at `index`, and the
+  /// elements after `index`.
+  ///
+  /// This does not allocate, and is O(n) on average.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `index` is greater than or equal to the length.
+  pub fn select_nth_unstable(
+    &mut self,
+    index: usize,
+  ) -> (SteadySliceMut<E>, &mut E, SteadySliceMut<E>)
+  where
+    E: Ord,
+  {
+    self.quickselect(index, &mut E::cmp)
+  }
+
+  /// Reorders the `SteadyVec` with a comparator function, such that the
+  /// element at `index` is at its final sorted position
+  ///
+  /// See [`select_nth_unstable`](Self::select_nth_unstable).
+  ///
+  /// # Panics
+  ///
+  /// Panics if `index` is greater than or equal to the length.
+  pub fn select_nth_unstable_by(
+    &mut self,
+    index: usize,
+    mut compare: impl FnMut(&E, &E) -> Ordering,
+  ) -> (SteadySliceMut<E>, &mut E, SteadySliceMut<E>) {
+    self.quickselect(index, &mut compare)
+  }
+
+  /// Reorders the `SteadyVec` with a key extraction function, such that the
+  /// element at `index` is at its final sorted position
+  ///
+  /// See [`select_nth_unstable`](Self::select_nth_unstable).
+  ///
+  /// # Panics
+  ///
+  /// Panics if `index` is greater than or equal to the length.
+  pub fn select_nth_unstable_by_key<K>(
+    &mut self,
+    index: usize,
+    mut f: impl FnMut(&E) -> K,
+  ) -> (SteadySliceMut<E>, &mut E, SteadySliceMut<E>)
+  where
+    K: Ord,
+  {
+    self.quickselect(index, &mut |a: &E, b: &E| f(a).cmp(&f(b)))
+  }
+
+  /// A stable merge sort
+  ///
+  /// Sorts each segment individually, then merges each segment in turn into
+  /// the sorted prefix before it. Each segment is the same size as all of the
+  /// segments before it combined, so the merges are balanced.
+  fn merge_sort<F>(&mut self, compare: &mut F)
+  where
+    F: FnMut(&E, &E) -> Ordering,
+  {
+    for segment in self.segments_mut() {
+      segment.sort_by(&mut *compare);
+    }
+
+    let segment_count = initialised_subarray_count(self.len);
+    if segment_count <= 1 {
+      return;
+    }
+
+    // The scratch space must be able to hold the largest segment after the
+    // first.
+    let scratch_len = (1..segment_count)
+      .map(|n| Segment::new(n, self.len).len())
+      .max()
+      .unwrap_or(0);
+    let mut owned_scratch: Vec<E> = Vec::new();
+    let scratch: *mut E = match self.subarrays.get(segment_count) {
+      // The next subarray is unused, and has twice the capacity of the last
+      // segment.
+      // safety: the subarray has a capacity greater than 0.
+      Some(Some(spare)) => unsafe { spare.element_ptr(0) },
+      // note: the `Vec` never has a length, so it will not drop the elements
+      // copied into it.
+      _ => {
+        owned_scratch.reserve_exact(scratch_len);
+        owned_scratch.as_mut_ptr()
+      },
+    };
+
+    let mut is_less = |a: &E, b: &E| compare(a, b) == Ordering::Less;
+    for subarray_n in 1..segment_count {
+      let (mid, _) = subarray_index_range(subarray_n);
+      let right_len = Segment::new(subarray_n, self.len).len();
+      // safety:
+      // - `0..mid` & `mid..mid + right_len` are initialised & sorted.
+      // - the scratch space can hold `right_len` elements, and does not
+      //   overlap any initialised elements.
+      unsafe { self.merge_segment(mid, right_len, scratch, &mut is_less) };
+    }
+  }
+
+  /// Merges the sorted range `mid..mid + right_len` into the sorted prefix
+  /// `0..mid`
+  ///
+  /// The right range is moved into `scratch`, then the elements are merged
+  /// from the back, filling the hole left behind.
+  ///
+  /// # Safety
+  ///
+  /// - `mid` must be greater than 0, and the elements from
+  ///   `0..mid + right_len` must be initialised.
+  /// - `mid..mid + right_len` must lie within a single subarray.
+  /// - `scratch` must be valid for writes of `right_len` elements, and must
+  ///   not overlap the initialised elements.
+  unsafe fn merge_segment(
+    &mut self,
+    mid: usize,
+    right_len: usize,
+    scratch: *mut E,
+    is_less: &mut impl FnMut(&E, &E) -> bool,
+  ) {
+    // safety: the elements are initialised, as the caller promises.
+    let already_sorted = unsafe {
+      !is_less(&*self.element_ptr(mid), &*self.element_ptr(mid - 1))
+    };
+    if already_sorted {
+      return;
+    }
+
+    // safety: the right range is contiguous, and the scratch is large enough.
+    unsafe {
+      ptr::copy_nonoverlapping(self.element_ptr(mid), scratch, right_len)
+    };
+
+    let mut hole = MergeHole {
+      steady_vec: self,
+      scratch,
+      left: mid,
+      right: right_len,
+    };
+    let mut left = RawCursor::new(mid - 1);
+    let mut out = RawCursor::new(mid + right_len - 1);
+
+    // Each iteration moves the greater of the last remaining left element and
+    // the last remaining right element into the back of the hole.
+    while hole.left > 0 && hole.right > 0 {
+      // safety:
+      // - `left` points to index `hole.left - 1`, which is initialised.
+      // - `hole.right - 1` is in bounds of the scratch space.
+      // - `out` points to index `hole.left + hole.right - 1`, which is the
+      //   last slot in the hole; it never aliases `left` since `hole.right`
+      //   is at least 1.
+      unsafe {
+        let left_ptr = left.ptr(hole.steady_vec);
+        let right_ptr = scratch.add(hole.right - 1);
+        let out_ptr = out.ptr(hole.steady_vec);
+        if is_less(&*right_ptr, &*left_ptr) {
+          ptr::copy_nonoverlapping(left_ptr, out_ptr, 1);
+          hole.left -= 1;
+          left.retreat();
+        } else {
+          ptr::copy_nonoverlapping(right_ptr, out_ptr, 1);
+          hole.right -= 1;
+        }
+      }
+      out.retreat();
+    }
+
+    // `hole` moves any remaining right elements into place when dropped.
+  }
+}
+
+// Unstable sorting & selection
+//
+// The quicksort below works directly on global indices. It only ever moves
+// elements with `ptr::swap`, so if the comparison panics every element is
+// still present exactly once.
+//
+// Whenever a range lies within a single subarray it is contiguous in memory,
+// so it is handed off to the equivalent `slice` method.
+impl<E> SteadyVec<E> {
+  fn quicksort<F>(&mut self, compare: &mut F)
+  where
+    F: FnMut(&E, &E) -> Ordering,
+  {
+    let limit = 2 * (usize::BITS - self.len.leading_zeros());
+    // safety: the range `0..len` is initialised, and `self` is borrowed
+    // mutably.
+    unsafe { self.quicksort_range(0..self.len, None, limit, compare) };
+  }
+
+  /// Sorts the elements in `range`
+  ///
+  /// `predecessor` is the index of an element before `range`, which is less
+  /// than or equal to every element in it. `limit` is the number of
+  /// imbalanced partitions allowed before switching to heapsort.
+  ///
+  /// # Safety
+  ///
+  /// - the elements in `range` must be initialised.
+  /// - the elements in `range` must not be accessed through any other pointer
+  ///   for the duration of the call.
+  unsafe fn quicksort_range<F>(
+    &self,
+    mut range: Range<usize>,
+    mut predecessor: Option<usize>,
+    mut limit: u32,
+    compare: &mut F,
+  ) where
+    F: FnMut(&E, &E) -> Ordering,
+  {
+    loop {
+      if range.len() <= 1 {
+        return;
+      }
+      // safety: the elements are initialised, as the caller promises.
+      if let Some(slice) = unsafe { self.contiguous_slice_mut(range.clone()) }
+      {
+        slice.sort_unstable_by(&mut *compare);
+        return;
+      }
+      if limit == 0 {
+        unsafe { self.heapsort(range, compare) };
+        return;
+      }
+
+      // safety: all indices below are within `range`.
+      unsafe {
+        let pivot = self.choose_pivot(range.clone(), compare);
+        self.swap_unchecked(range.start, pivot);
+
+        // If the pivot is equal to the predecessor, then it is the smallest
+        // element in the range. Move every element equal to it to the left,
+        // and continue with only the greater elements.
+        if let Some(predecessor) = predecessor {
+          if !is_less(compare, predecessor, range.start, self) {
+            let mid = self.partition(range.clone(), |element, pivot| {
+              compare(pivot, element) != Ordering::Less
+            });
+            range.start = mid;
+            continue;
+          }
+        }
+
+        let mid = self.partition(range.clone(), |element, pivot| {
+          compare(element, pivot) == Ordering::Less
+        }) - 1;
+        // move the pivot into its final position
+        self.swap_unchecked(range.start, mid);
+
+        let (left, right) = (range.start..mid, (mid + 1)..range.end);
+        if left.len().min(right.len()) < range.len() / 8 {
+          limit -= 1;
+        }
+        // Recurse into the shorter side only, to bound the stack depth.
+        if left.len() < right.len() {
+          self.quicksort_range(left, predecessor, limit, compare);
+          range = right;
+          predecessor = Some(mid);
+        } else {
+          self.quicksort_range(right, Some(mid), limit, compare);
+          range = left;
+        }
+      }
+    }
+  }
+
+  fn quickselect<F>(
+    &mut self,
+    index: usize,
+    compare: &mut F,
+  ) -> (SteadySliceMut<E>, &mut E, SteadySliceMut<E>)
+  where
+    F: FnMut(&E, &E) -> Ordering,
+  {
+    if index >= self.len {
+      panic!(
+        "index is out of bounds, index: {index}, len: {len}",
+        len = self.len
+      );
+    }
+
+    let mut range = 0..self.len;
+    let mut predecessor = None;
+    let mut limit = 2 * (usize::BITS - self.len.leading_zeros());
+    // safety: every range below is a subset of `0..len`, and contains `index`.
+    // `self` is borrowed mutably, so nothing else can access the elements.
+    unsafe {
+      loop {
+        if let Some(slice) = self.contiguous_slice_mut(range.clone()) {
+          slice.select_nth_unstable_by(index - range.start, &mut *compare);
+          break;
+        }
+        if limit == 0 {
+          self.heapsort(range, compare);
+          break;
+        }
+
+        let pivot = self.choose_pivot(range.clone(), compare);
+        self.swap_unchecked(range.start, pivot);
+
+        if let Some(predecessor) = predecessor {
+          if !is_less(compare, predecessor, range.start, self) {
+            let mid = self.partition(range.clone(), |element, pivot| {
+              compare(pivot, element) != Ordering::Less
+            });
+            if index < mid {
+              // every element in `range.start..mid` is equal
+              break;
+            }
+            range.start = mid;
+            continue;
+          }
+        }
+
+        let mid = self.partition(range.clone(), |element, pivot| {
+          compare(element, pivot) == Ordering::Less
+        }) - 1;
+        self.swap_unchecked(range.start, mid);
+
+        let (left, right) = (range.start..mid, (mid + 1)..range.end);
+        if left.len().min(right.len()) < range.len() / 8 {
+          limit -= 1;
+        }
+        match index.cmp(&mid) {
+          Ordering::Equal => break,
+          Ordering::Less => range = left,
+          Ordering::Greater => {
+            range = right;
+            predecessor = Some(mid);
+          },
+        }
+      }
+    }
+
+    let len = self.len;
+    let steady_vec: &SteadyVec<E> = self;
+    // safety: the views & the element are disjoint, and `self` is borrowed
+    // mutably for their lifetime.
+    unsafe {
+      (
+        SteadySliceMut::from_raw_parts(steady_vec, 0..index),
+        &mut *steady_vec.element_ptr(index),
+        SteadySliceMut::from_raw_parts(steady_vec, (index + 1)..len),
+      )
+    }
+  }
+
+  /// Get the elements in `range` as a slice, if they lie within a single
+  /// subarray
+  ///
+  /// # Safety
+  ///
+  /// - the elements in `range` must be initialised, and must not be accessed
+  ///   through any other pointer while the slice is in use.
+  unsafe fn contiguous_slice_mut<'a>(
+    &self,
+    range: Range<usize>,
+  ) -> Option<&'a mut [E]> {
+    if index_to_subarray_n(range.start) != index_to_subarray_n(range.end - 1) {
+      return None;
+    }
+
+    // safety: the range is within a single subarray.
+    let slice = unsafe {
+      ::core::slice::from_raw_parts_mut(
+        self.element_ptr(range.start),
+        range.len(),
+      )
+    };
+    Some(slice)
+  }
+
+  /// Partitions the elements in `range.start + 1..range.end` around the pivot
+  /// at `range.start`
+  ///
+  /// Elements for which `goes_left(element, pivot)` returns `true` are moved
+  /// before the others. Returns the index of the first element that was not
+  /// moved left. The pivot itself is not moved.
+  ///
+  /// # Safety
+  ///
+  /// - the elements in `range` must be initialised, and `range` must contain
+  ///   at least 1 element.
+  /// - the elements in `range` must not be accessed through any other pointer
+  ///   for the duration of the call.
+  unsafe fn partition(
+    &self,
+    range: Range<usize>,
+    mut goes_left: impl FnMut(&E, &E) -> bool,
+  ) -> usize {
+    // safety: the range contains at least the pivot. The pivot is not moved
+    // by the loop, which only swaps elements after it.
+    let pivot = unsafe { &*self.element_ptr(range.start) };
+    let mut left = RawCursor::new(range.start + 1);
+    let mut right = RawCursor::new(range.end - 1);
+
+    // Elements before `left` go left, and elements after `right` do not.
+    // safety: the cursors are always within `range` when dereferenced.
+    unsafe {
+      loop {
+        while left.index() <= right.index()
+          && goes_left(&*left.ptr(self), pivot)
+        {
+          left.advance();
+        }
+        while left.index() <= right.index()
+          && !goes_left(&*right.ptr(self), pivot)
+        {
+          right.retreat();
+        }
+        if left.index() > right.index() {
+          break;
+        }
+        ptr::swap(left.ptr(self), right.ptr(self));
+        left.advance();
+        right.retreat();
+      }
+    }
+
+    left.index()
+  }
+
+  /// Chooses the index of a pivot in `range`, using the median of three
+  /// elements
+  ///
+  /// # Safety
+  ///
+  /// - the elements in `range` must be initialised, and `range` must contain
+  ///   at least 1 element.
+  /// - the elements in `range` must not be accessed through any other pointer
+  ///   for the duration of the call.
+  unsafe fn choose_pivot<F>(
+    &self,
+    range: Range<usize>,
+    compare: &mut F,
+  ) -> usize
+  where
+    F: FnMut(&E, &E) -> Ordering,
+  {
+    let mut a = range.start;
+    let mut b = range.start + range.len() / 2;
+    let mut c = range.end - 1;
+
+    // safety: the indices are all within `range`.
+    unsafe {
+      if is_less(compare, b, a, self) {
+        ::core::mem::swap(&mut a, &mut b);
+      }
+      if is_less(compare, c, b, self) {
+        ::core::mem::swap(&mut b, &mut c);
+        if is_less(compare, b, a, self) {
+          ::core::mem::swap(&mut a, &mut b);
+        }
+      }
+    }
+
+    b
+  }
+
+  /// Sorts the elements in `range` using heapsort
+  ///
+  /// # Safety
+  ///
+  /// - the elements in `range` must be initialised.
+  /// - the elements in `range` must not be accessed through any other pointer
+  ///   for the duration of the call.
+  unsafe fn heapsort<F>(&self, range: Range<usize>, compare: &mut F)
+  where
+    F: FnMut(&E, &E) -> Ordering,
+  {
+    let offset = range.start;
+    // Restores the heap property for the sub-heap rooted at `node`, within
+    // the first `end` elements of the range.
+    let mut sift_down = |mut node: usize, end: usize| loop {
+      let mut child = 2 * node + 1;
+      if child >= end {
+        break;
+      }
+      // safety: `node` & the children are less than `end`, which is within
+      // `range`.
+      unsafe {
+        if child + 1 < end
+          && is_less(compare, offset + child, offset + child + 1, self)
+        {
+          child += 1;
+        }
+        if !is_less(compare, offset + node, offset + child, self) {
+          break;
+        }
+        self.swap_unchecked(offset + node, offset + child);
+      }
+      node = child;
+    };
+
+    let len = range.len();
+    for node in (0..len / 2).rev() {
+      sift_down(node, len);
+    }
+    for end in (1..len).rev() {
+      // safety: `end` is within `range`.
+      unsafe { self.swap_unchecked(offset, offset + end) };
+      sift_down(0, end);
+    }
+  }
+
+  /// Swap the elements at `a` & `b`
+  ///
+  /// # Safety
+  ///
+  /// - the elements at `a` & `b` must be initialised, and must not be accessed
+  ///   through any other pointer during the swap.
+  #[inline]
+  unsafe fn swap_unchecked(&self, a: usize, b: usize) {
+    unsafe { ptr::swap(self.element_ptr(a), self.element_ptr(b)) };
+  }
+}
+
+/// Returns `true` if the element at `a` is less than the element at `b`
+///
+/// # Safety
+///
+/// - the elements at `a` & `b` must be initialised.
+#[inline]
+unsafe fn is_less<E, F>(
+  compare: &mut F,
+  a: usize,
+  b: usize,
+  steady_vec: &SteadyVec<E>,
+) -> bool
+where
+  F: FnMut(&E, &E) -> Ordering,
+{
+  unsafe {
+    compare(&*steady_vec.element_ptr(a), &*steady_vec.element_ptr(b))
+      == Ordering::Less
+  }
+}
+
+/// A guard used while merging
+///
+/// The elements from `scratch[0..right]` belong in the hole at the indices
+/// `left..left + right`. When dropped, the remaining elements are moved from
+/// the scratch space into the hole. This happens even if the comparison
+/// panics, so that every element is left in the `SteadyVec` exactly once.
+struct MergeHole<'s, E> {
+  steady_vec: &'s mut SteadyVec<E>,
+  scratch: *const E,
+  left: usize,
+  right: usize,
+}
+
+impl<'s, E> Drop for MergeHole<'s, E> {
+  fn drop(&mut self) {
+    // safety:
+    // - the hole's subarrays exist, since they were previously initialised.
+    // - the scratch space does not overlap the initialised elements.
+    unsafe {
+      self.steady_vec.copy_from_ptr_unchecked(
+        self.scratch,
+        self.left,
+        self.right,
+      )
+    };
+  }
+}
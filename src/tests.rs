@@ -1,4 +1,56 @@
-use super::*;
+use {
+  super::*,
+  ::core::cell::Cell,
+  ::std::{
+    panic::{catch_unwind, AssertUnwindSafe},
+    rc::Rc,
+    vec::Vec,
+  },
+};
+
+/// Counts how many times it is dropped
+struct DropCounter<'c>(&'c Cell<usize>);
+
+impl Drop for DropCounter<'_> {
+  fn drop(&mut self) {
+    self.0.set(self.0.get() + 1);
+  }
+}
+
+/// Hands out tokens, and counts how many of them are still alive
+///
+/// Used to check that every element is dropped exactly once.
+struct Tokens(Rc<()>);
+
+impl Tokens {
+  fn new() -> Self {
+    Tokens(Rc::new(()))
+  }
+
+  fn token(&self) -> Rc<()> {
+    self.0.clone()
+  }
+
+  fn alive(&self) -> usize {
+    Rc::strong_count(&self.0) - 1
+  }
+}
+
+/// Runs `f`, returning `true` if it panicked
+fn panics(f: impl FnOnce()) -> bool {
+  catch_unwind(AssertUnwindSafe(f)).is_err()
+}
+
+/// A simple deterministic pseudo-random sequence (xorshift64)
+fn xorshift(seed: u64) -> impl FnMut() -> u64 {
+  let mut state = seed;
+  move || {
+    state ^= state << 13;
+    state ^= state >> 7;
+    state ^= state << 17;
+    state
+  }
+}
 
 #[test]
 fn meta() {
@@ -153,3 +205,1137 @@ fn smoke() {
     }
   }
 }
+
+#[test]
+fn reserve() {
+  // reserving on an empty `SteadyVec` must not underflow
+  let mut v: SteadyVec<usize> = SteadyVec::new();
+  v.reserve(0);
+  v.reserve(100);
+  assert!(v.capacity() >= 100);
+  assert!(v.is_empty());
+  v.extend(0..100);
+  assert!(v.iter().copied().eq(0..100));
+}
+
+#[test]
+fn segments() {
+  let mut v: SteadyVec<usize> = SteadyVec::new();
+  assert_eq!(v.segments().count(), 0);
+  assert_eq!(v.segment_ranges().count(), 0);
+
+  v.extend(0..21);
+  let lens: Vec<usize> = v.segments().map(|s| s.len()).collect();
+  assert_eq!(lens, [4, 4, 8, 5]);
+
+  // the concatenated segments match the elements
+  let flat: Vec<usize> = v.segments().flatten().copied().collect();
+  assert_eq!(flat, (0..21).collect::<Vec<_>>());
+
+  // ranges describe the global indices of each segment
+  for (segment, slice) in ::core::iter::zip(v.segment_ranges(), v.segments()) {
+    assert_eq!(segment.len(), slice.len());
+    for (index, e) in ::core::iter::zip(segment.range(), slice) {
+      assert_eq!(v.get(index), Some(e));
+    }
+  }
+  let last = v.segment_ranges().next_back().unwrap();
+  assert_eq!(last.subarray_n(), 3);
+  assert_eq!(last.range(), 16..21);
+  assert_eq!(last.capacity(), 16);
+
+  for (segment, slice) in
+    ::core::iter::zip(v.segment_ranges(), v.segments_mut())
+  {
+    slice.copy_from_slice(&segment.range().map(|i| i * 2).collect::<Vec<_>>());
+  }
+  for (i, e) in v.iter().enumerate() {
+    assert_eq!(*e, i * 2);
+  }
+  assert_eq!(v.segments_mut().next_back().map(|s| s.len()), Some(5));
+}
+
+#[test]
+fn slice() {
+  let mut v: SteadyVec<usize> = SteadyVec::new();
+  v.extend(0..100);
+
+  let s = v.slice(5..50);
+  assert_eq!(s.len(), 45);
+  assert_eq!(s.range(), 5..50);
+  assert_eq!(s.first(), Some(&5));
+  assert_eq!(s.last(), Some(&49));
+  assert_eq!(s[10], 15);
+  assert_eq!(s.get(45), None);
+  assert!(s.iter().copied().eq(5..50));
+  assert!(s.iter().rev().copied().eq((5..50).rev()));
+
+  let (a, b) = s.split_at(3);
+  assert!(a.iter().copied().eq(5..8));
+  assert!(b.iter().copied().eq(8..50));
+  assert!(b.slice(2..=4).iter().copied().eq(10..13));
+  assert!(v.slice(..).iter().copied().eq(0..100));
+  assert!(v.slice(100..).is_empty());
+  assert_eq!(v.slice(100..).last(), None);
+
+  // the iterator does not run past the end of the view from either side
+  let mut iter = v.slice(10..12).iter();
+  assert_eq!(iter.next_back(), Some(&11));
+  assert_eq!(iter.next(), Some(&10));
+  assert_eq!(iter.next_back(), None);
+  assert_eq!(iter.next(), None);
+
+  let mut s = v.slice_mut(20..30);
+  for e in s.iter_mut() {
+    *e += 1000;
+  }
+  s[0] = 0;
+  *s.last_mut().unwrap() = 1;
+  let mut inner = s.slice_mut(1..3);
+  *inner.get_mut(1).unwrap() = 2;
+  assert_eq!(inner.get_mut(2), None);
+  assert_eq!(v[20], 0);
+  assert_eq!(v[21], 1021);
+  assert_eq!(v[22], 2);
+  assert_eq!(v[29], 1);
+  assert_eq!(v[30], 30);
+}
+
+#[test]
+#[should_panic]
+fn slice_out_of_bounds() {
+  let mut v: SteadyVec<usize> = SteadyVec::new();
+  v.extend(0..10);
+  v.slice(5..11);
+}
+
+#[test]
+fn split_at_mut() {
+  let mut v: SteadyVec<usize> = SteadyVec::new();
+  v.extend(0..40);
+
+  let (mut left, mut right) = v.split_at_mut(10);
+  assert_eq!(left.len(), 10);
+  assert_eq!(right.len(), 30);
+  // update a node from its neighbour
+  *left.last_mut().unwrap() += *right.first().unwrap();
+  *right.get_mut(0).unwrap() += left[0];
+
+  // split further, and use all of the views at once
+  let (mut a, mut b) = right.split_at_mut(15);
+  let (mut c, mut d) = left.split_at_mut(5);
+  for e in a.iter_mut().chain(b.iter_mut()) {
+    *e += 100;
+  }
+  for e in c.iter_mut().chain(&mut d) {
+    *e *= 2;
+  }
+  assert_eq!(a.get_mut(15), None);
+  let (_, tail) = b.into_split_at_mut(14);
+  assert_eq!(tail.len(), 1);
+  for e in tail {
+    *e = 0;
+  }
+
+  assert_eq!(v[0], 0);
+  assert_eq!(v[4], 8);
+  assert_eq!(v[9], 38);
+  assert_eq!(v[10], 110);
+  assert_eq!(v[11], 111);
+  assert_eq!(v[38], 138);
+  assert_eq!(v[39], 0);
+}
+
+#[test]
+fn drain() {
+  let mut v: SteadyVec<usize> = SteadyVec::new();
+  v.extend(0..100);
+
+  // drain across several subarrays
+  let drained: Vec<usize> = v.drain(3..40).collect();
+  assert_eq!(drained, (3..40).collect::<Vec<_>>());
+  assert_eq!(v.len(), 63);
+  assert!(v.iter().copied().eq((0..3).chain(40..100)));
+
+  // double ended, and dropped early
+  let mut drain = v.drain(10..20);
+  assert_eq!(drain.len(), 10);
+  assert_eq!(drain.next(), Some(47));
+  assert_eq!(drain.next_back(), Some(56));
+  assert_eq!(drain.len(), 8);
+  drop(drain);
+  assert!(v.iter().copied().eq((0..3).chain(40..47).chain(57..100)));
+
+  v.drain(..);
+  assert!(v.is_empty());
+
+  // remaining elements are dropped exactly once
+  let tokens = Tokens::new();
+  let mut w: SteadyVec<Rc<()>> = SteadyVec::new();
+  w.resize(50, tokens.token());
+  let taken: Vec<_> = w.drain(5..30).take(3).collect();
+  assert_eq!(tokens.alive(), 25 + 3);
+  drop(taken);
+  assert_eq!(w.len(), 25);
+
+  drop(w);
+  assert_eq!(tokens.alive(), 0);
+
+  // leaking the iterator leaks the range & tail, but leaves the vec valid.
+  // The elements own no heap memory, so nothing is reported leaked by Miri.
+  let drops = Cell::new(0);
+  let mut w: SteadyVec<DropCounter> =
+    (0..25).map(|_| DropCounter(&drops)).collect();
+  ::core::mem::forget(w.drain(10..15));
+  assert_eq!(w.len(), 10);
+  w.push(DropCounter(&drops));
+  drop(w);
+  assert_eq!(drops.get(), 11);
+}
+
+#[test]
+fn retain() {
+  let mut v: SteadyVec<usize> = SteadyVec::new();
+  v.extend(0..1000);
+  v.retain(|e| e % 3 == 0);
+  assert!(v.iter().copied().eq((0..1000).step_by(3)));
+
+  v.retain_mut(|e| {
+    *e += 1;
+    *e < 100
+  });
+  assert!(v.iter().copied().eq((1..100).step_by(3)));
+
+  v.retain(|_| false);
+  assert!(v.is_empty());
+
+  // every element is dropped exactly once, even if the predicate panics
+  let tokens = Tokens::new();
+  let mut w: SteadyVec<(usize, Rc<()>)> =
+    (0..100).map(|i| (i, tokens.token())).collect();
+  assert!(panics(|| {
+    w.retain(|(i, _)| {
+      assert!(*i != 50);
+      i % 2 == 0
+    })
+  }));
+  let remaining: Vec<usize> = w.iter().map(|(i, _)| *i).collect();
+  let expected: Vec<usize> = (0..50).step_by(2).chain(50..100).collect();
+  assert_eq!(remaining, expected);
+  assert_eq!(tokens.alive(), expected.len());
+  drop(w);
+  assert_eq!(tokens.alive(), 0);
+}
+
+#[test]
+fn dedup() {
+  let mut v: SteadyVec<usize> = (0..300).map(|i| i / 7).collect();
+  v.dedup();
+  assert!(v.iter().copied().eq(0..43));
+
+  let mut v: SteadyVec<usize> = (0..100).collect();
+  v.dedup_by_key(|e| *e / 10);
+  assert!(v.iter().copied().eq((0..100).step_by(10)));
+
+  // `b` is the previously kept element
+  let mut v: SteadyVec<usize> =
+    [1, 2, 3, 10, 11, 25, 26, 27].into_iter().collect();
+  v.dedup_by(|a, b| *a - *b < 5);
+  assert!(v.iter().copied().eq([1, 10, 25]));
+
+  let mut v: SteadyVec<usize> = SteadyVec::new();
+  v.dedup();
+  v.push(1);
+  v.dedup();
+  assert_eq!(v.len(), 1);
+
+  // every element is dropped exactly once, even if the comparator panics
+  let tokens = Tokens::new();
+  let mut w: SteadyVec<(usize, Rc<()>)> =
+    (0..100).map(|i| (i / 2, tokens.token())).collect();
+  assert!(panics(|| {
+    w.dedup_by(|(a, _), (b, _)| {
+      assert!(*a != 30);
+      a == b
+    })
+  }));
+  let remaining: Vec<usize> = w.iter().map(|(i, _)| *i).collect();
+  let expected: Vec<usize> = (0..30).chain((60..100).map(|i| i / 2)).collect();
+  assert_eq!(remaining, expected);
+  assert_eq!(tokens.alive(), expected.len());
+}
+
+#[test]
+fn splice() {
+  let mut v: SteadyVec<usize> = (0..100).collect();
+
+  // replace with fewer elements
+  let removed: Vec<usize> = v.splice(10..30, [1, 2, 3]).collect();
+  assert_eq!(removed, (10..30).collect::<Vec<_>>());
+  assert!(v
+    .iter()
+    .copied()
+    .eq((0..10).chain([1, 2, 3]).chain(30..100)));
+
+  // replace with more elements, with an exact size hint
+  v.splice(10..13, 200..250);
+  assert!(v.iter().copied().eq((0..10).chain(200..250).chain(30..100)));
+
+  // replace with more elements, with an inexact size hint
+  v.splice(10..60, (300..400).filter(|e| e % 2 == 0));
+  let expected: Vec<usize> = (0..10)
+    .chain((300..400).step_by(2))
+    .chain(30..100)
+    .collect();
+  assert!(v.iter().copied().eq(expected.iter().copied()));
+
+  // insert without removing, and splice at the end
+  v.splice(0..0, [7, 7]);
+  assert!(v.iter().take(3).copied().eq([7, 7, 0]));
+  let len = v.len();
+  v.splice(len.., 0..5).for_each(drop);
+  assert_eq!(v.len(), len + 5);
+  assert!(v.iter().rev().take(5).copied().eq((0..5).rev()));
+
+  v.splice(.., []);
+  assert!(v.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "capacity")]
+fn splice_size_hint_overflow() {
+  // an iterator that claims far more elements than could ever fit
+  struct Liar;
+  impl Iterator for Liar {
+    type Item = usize;
+    fn next(&mut self) -> Option<usize> {
+      Some(0)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+      (usize::MAX, None)
+    }
+  }
+
+  let mut v: SteadyVec<usize> = (0..100).collect();
+  v.splice(10..20, Liar);
+}
+
+#[test]
+fn split_off_append() {
+  let mut v: SteadyVec<usize> = (0..100).collect();
+  let mut w = v.split_off(37);
+  assert!(v.iter().copied().eq(0..37));
+  assert!(w.iter().copied().eq(37..100));
+  assert!(v.split_off(37).is_empty());
+
+  v.append(&mut w);
+  assert!(w.is_empty());
+  assert!(v.iter().copied().eq(0..100));
+
+  let mut empty = SteadyVec::new();
+  v.append(&mut empty);
+  empty.append(&mut v);
+  assert!(v.is_empty());
+  assert!(empty.iter().copied().eq(0..100));
+  let all = empty.split_off(0);
+  assert!(empty.is_empty());
+  assert_eq!(all.len(), 100);
+
+  // elements are moved, not cloned or dropped
+  let tokens = Tokens::new();
+  let mut a: SteadyVec<Rc<()>> = SteadyVec::new();
+  a.resize(30, tokens.token());
+  let mut b = a.split_off(10);
+  a.append(&mut b);
+  b.append(&mut a);
+  assert_eq!(b.len(), 30);
+  assert_eq!(tokens.alive(), 30);
+  drop(b);
+  assert_eq!(tokens.alive(), 0);
+}
+
+#[test]
+fn extend_from_slice() {
+  let source: Vec<usize> = (0..100).collect();
+  let mut v: SteadyVec<usize> = SteadyVec::new();
+  v.extend_from_slice(&[]);
+  assert!(v.is_empty());
+  v.extend_from_slice(&source[..3]);
+  v.extend_from_slice(&source[3..]);
+  assert!(v.iter().copied().eq(0..100));
+
+  v.extend_from_within(90..);
+  v.extend_from_within(..=2);
+  assert!(v.iter().copied().eq((0..100).chain(90..100).chain(0..3)));
+  v.extend_from_within(..);
+  assert_eq!(v.len(), 226);
+  assert!(v
+    .iter()
+    .skip(113)
+    .copied()
+    .eq((0..100).chain(90..100).chain(0..3)));
+
+  let tokens = Tokens::new();
+  let mut w: SteadyVec<Rc<()>> = SteadyVec::new();
+  w.extend_from_slice(&[tokens.token(), tokens.token()]);
+  w.extend_from_within(..);
+  w.extend_from_within(1..);
+  assert_eq!(w.len(), 7);
+  assert_eq!(tokens.alive(), 7);
+  drop(w);
+  assert_eq!(tokens.alive(), 0);
+
+  // the elements cloned before a `Clone` panics are dropped exactly once
+  struct PanicOnClone(usize, Rc<()>);
+  impl Clone for PanicOnClone {
+    fn clone(&self) -> Self {
+      assert!(self.0 != 20);
+      PanicOnClone(self.0, self.1.clone())
+    }
+  }
+  let source: Vec<PanicOnClone> =
+    (0..30).map(|i| PanicOnClone(i, tokens.token())).collect();
+  let mut w: SteadyVec<PanicOnClone> = SteadyVec::new();
+  w.extend_from_slice(&source[..2]);
+  assert!(panics(|| w.extend_from_slice(&source[2..])));
+  assert!(w.iter().map(|e| e.0).eq(0..20));
+  assert_eq!(tokens.alive(), 30 + 20);
+  drop(w);
+  assert_eq!(tokens.alive(), 30);
+}
+
+#[test]
+fn sort() {
+  let mut random = xorshift(0x2545f4914f6cdd1d);
+
+  for len in [0, 1, 3, 4, 5, 8, 17, 100, 1000, 1500] {
+    let source: Vec<u64> = (0..len).map(|_| random() % 50).collect();
+    let mut expected = source.clone();
+    expected.sort();
+
+    let mut v: SteadyVec<u64> = source.iter().copied().collect();
+    v.sort();
+    assert!(v.iter().eq(expected.iter()));
+
+    // the same, but with spare capacity available as scratch space
+    let mut v: SteadyVec<u64> = source.iter().copied().collect();
+    v.reserve(v.len() + 1);
+    v.sort_by(|a, b| b.cmp(a));
+    assert!(v.iter().eq(expected.iter().rev()));
+
+    // stability
+    let mut v: SteadyVec<(u64, usize)> =
+      source.iter().copied().zip(0..).collect();
+    v.sort_by_key(|&(key, _)| key);
+    let mut expected: Vec<(u64, usize)> =
+      source.iter().copied().zip(0..).collect();
+    expected.sort_by_key(|&(key, _)| key);
+    assert!(v.iter().eq(expected.iter()));
+
+    let mut v: SteadyVec<(u64, usize)> =
+      source.iter().copied().zip(0..).collect();
+    v.sort_by_cached_key(|&(key, _)| key.to_string());
+    expected.sort_by_cached_key(|&(key, _)| key.to_string());
+    assert!(v.iter().eq(expected.iter()));
+  }
+
+  // every element is dropped exactly once, even if the comparator panics
+  // while the sorted segments are being merged
+  let tokens = Tokens::new();
+  let source: Vec<(u64, Rc<()>)> =
+    (0..200).map(|_| (random() % 100, tokens.token())).collect();
+  let mut keys: Vec<u64> = source.iter().map(|(key, _)| *key).collect();
+  keys.sort();
+
+  // find how many comparisons the segment sorts use, so the panic can be
+  // placed in the merges that follow them
+  let mut segment_comparisons = 0;
+  let mut v: SteadyVec<(u64, Rc<()>)> = source.iter().cloned().collect();
+  for segment in v.segments_mut() {
+    segment.sort_by(|(a, _), (b, _)| {
+      segment_comparisons += 1;
+      a.cmp(b)
+    });
+  }
+  let mut total_comparisons = 0;
+  v = source.iter().cloned().collect();
+  v.sort_by(|(a, _), (b, _)| {
+    total_comparisons += 1;
+    a.cmp(b)
+  });
+  assert!(total_comparisons > segment_comparisons);
+  drop(v);
+
+  // with a temporary buffer, & with a spare subarray as scratch space
+  for spare_capacity in [false, true] {
+    for panic_at in (segment_comparisons + 1..=total_comparisons).step_by(37) {
+      let mut v: SteadyVec<(u64, Rc<()>)> = source.iter().cloned().collect();
+      if spare_capacity {
+        v.reserve(v.len() + 1);
+      }
+      let mut comparisons = 0;
+      assert!(panics(|| {
+        v.sort_by(|(a, _), (b, _)| {
+          comparisons += 1;
+          assert!(comparisons < panic_at);
+          a.cmp(b)
+        })
+      }));
+      assert_eq!(v.len(), 200);
+      assert_eq!(tokens.alive(), 400);
+      let mut remaining: Vec<u64> = v.iter().map(|(key, _)| *key).collect();
+      remaining.sort();
+      assert_eq!(remaining, keys);
+    }
+  }
+  drop(source);
+  assert_eq!(tokens.alive(), 0);
+}
+
+#[test]
+fn sort_unstable() {
+  let mut random = xorshift(0x9e3779b97f4a7c15);
+
+  for len in [0, 1, 2, 5, 8, 17, 100, 1000, 3000] {
+    for modulus in [2, 50, u64::MAX] {
+      let source: Vec<u64> = (0..len).map(|_| random() % modulus).collect();
+      let mut expected = source.clone();
+      expected.sort();
+
+      let mut v: SteadyVec<u64> = source.iter().copied().collect();
+      v.sort_unstable();
+      assert!(v.iter().eq(expected.iter()));
+      // already sorted, & reversed input
+      v.sort_unstable_by(|a, b| b.cmp(a));
+      assert!(v.iter().eq(expected.iter().rev()));
+      v.sort_unstable_by_key(|&e| e);
+      assert!(v.iter().eq(expected.iter()));
+
+      if len == 0 {
+        continue;
+      }
+      for index in [0, len / 2, len * 99 / 100, len - 1] {
+        let mut v: SteadyVec<u64> = source.iter().copied().collect();
+        let (left, nth, right) = v.select_nth_unstable(index);
+        assert_eq!(*nth, expected[index]);
+        assert_eq!(left.len(), index);
+        assert_eq!(right.len(), len - index - 1);
+        let nth = *nth;
+        assert!(left.iter().all(|&e| e <= nth));
+        assert!(right.iter().all(|&e| e >= nth));
+
+        let (_, nth, _) = v.select_nth_unstable_by_key(index, |&e| !e);
+        assert_eq!(*nth, expected[len - 1 - index]);
+      }
+    }
+  }
+
+  // every element is dropped exactly once, even if the comparator panics
+  let tokens = Tokens::new();
+  let mut v: SteadyVec<(u64, Rc<()>)> =
+    (0..300).map(|_| (random() % 100, tokens.token())).collect();
+  let mut comparisons = 0;
+  assert!(panics(|| {
+    v.sort_unstable_by(|(a, _), (b, _)| {
+      comparisons += 1;
+      assert!(comparisons < 200);
+      a.cmp(b)
+    })
+  }));
+  assert_eq!(v.len(), 300);
+  assert_eq!(tokens.alive(), 300);
+}
+
+#[test]
+#[should_panic]
+fn select_nth_unstable_out_of_bounds() {
+  let mut v: SteadyVec<usize> = (0..10).collect();
+  v.select_nth_unstable(10);
+}
+
+#[test]
+fn binary_search() {
+  for len in [0, 1, 4, 5, 37, 100, 1000] {
+    // every value appears twice
+    let source: Vec<usize> = (0..len).map(|i| i / 2 * 3).collect();
+    let v: SteadyVec<usize> = source.iter().copied().collect();
+
+    for x in 0..(len * 3 / 2 + 3) {
+      match (v.binary_search(&x), source.binary_search(&x)) {
+        (Ok(a), Ok(_)) => assert_eq!(v[a], x),
+        (a, b) => assert_eq!(a, b),
+      }
+      assert_eq!(
+        v.partition_point(|&e| e < x),
+        source.partition_point(|&e| e < x)
+      );
+      assert_eq!(
+        v.binary_search_by_key(&x, |&e| e).is_ok(),
+        source.binary_search(&x).is_ok()
+      );
+    }
+  }
+
+  let v: SteadyVec<usize> = (0..100).collect();
+  assert_eq!(v.binary_search(&0), Ok(0));
+  assert_eq!(v.binary_search(&4), Ok(4));
+  assert_eq!(v.binary_search(&99), Ok(99));
+  assert_eq!(v.binary_search(&100), Err(100));
+  assert_eq!(v.binary_search_by(|e| e.cmp(&7)), Ok(7));
+  assert_eq!(v.partition_point(|_| true), 100);
+  assert_eq!(v.partition_point(|_| false), 0);
+}
+
+#[test]
+fn reverse_rotate() {
+  for len in [0, 1, 2, 5, 8, 33, 100] {
+    let source: Vec<usize> = (0..len).collect();
+
+    let mut v: SteadyVec<usize> = source.iter().copied().collect();
+    v.reverse();
+    assert!(v.iter().eq(source.iter().rev()));
+
+    for mid in [0, 1, len / 3, len / 2, len.saturating_sub(1), len] {
+      if mid > len {
+        continue;
+      }
+      let mut expected = source.clone();
+      expected.rotate_left(mid);
+      let mut v: SteadyVec<usize> = source.iter().copied().collect();
+      v.rotate_left(mid);
+      assert!(v.iter().eq(expected.iter()));
+
+      let mut expected = source.clone();
+      expected.rotate_right(mid);
+      let mut v: SteadyVec<usize> = source.iter().copied().collect();
+      v.rotate_right(mid);
+      assert!(v.iter().eq(expected.iter()));
+    }
+  }
+}
+
+#[test]
+fn fill_contains() {
+  let mut v: SteadyVec<u8> = (0..100).collect();
+  assert!(v.contains(&0));
+  assert!(v.contains(&99));
+  assert!(!v.contains(&100));
+
+  assert!(v.starts_with(&[]));
+  assert!(v.starts_with(&(0..10).collect::<Vec<_>>()));
+  assert!(!v.starts_with(&(1..11).collect::<Vec<_>>()));
+  assert!(v.ends_with(&(90..100).collect::<Vec<_>>()));
+  assert!(!v.ends_with(&(89..99).collect::<Vec<_>>()));
+  assert!(v.starts_with(&(0..100).collect::<Vec<_>>()));
+  assert!(!v.ends_with(&(0..101).collect::<Vec<_>>()));
+
+  let mut iter = v.iter();
+  assert_eq!(iter.position(|&e| e == 20), Some(20));
+  assert_eq!(iter.next(), Some(&21));
+  assert_eq!(iter.position(|&e| e == 23), Some(1));
+  assert_eq!(iter.rposition(|&e| e == 50), Some(26));
+  assert_eq!(iter.next_back(), Some(&49));
+  assert_eq!(iter.position(|&e| e == 99), None);
+  assert_eq!(iter.next(), None);
+
+  let sum = v.iter().fold(0usize, |acc, &e| acc + e as usize);
+  assert_eq!(sum, (0..100).sum());
+  let reversed: Vec<u8> = v.iter().rev().copied().collect();
+  assert!(reversed
+    .iter()
+    .eq((0..100).rev().collect::<Vec<_>>().iter()));
+
+  v.fill(7);
+  assert!(v.iter().all(|&e| e == 7));
+  let mut count = 0;
+  v.fill_with(|| {
+    count += 1;
+    count
+  });
+  assert!(v.iter().copied().eq(1..=100));
+}
+
+#[test]
+fn insert_many() {
+  for len in [0, 3, 4, 9, 40] {
+    for index in [0, len / 2, len] {
+      for count in [0, 1, 5, 30] {
+        let source: Vec<usize> = (0..len).collect();
+        let inserted: Vec<usize> = (100..100 + count).collect();
+        let mut expected = source.clone();
+        expected.splice(index..index, inserted.iter().copied());
+
+        let mut v: SteadyVec<usize> = source.iter().copied().collect();
+        v.insert_from_slice(index, &inserted);
+        assert_eq!(v.len(), len + count);
+        assert!(v.iter().eq(expected.iter()));
+
+        // an iterator without an exact size hint
+        let mut v: SteadyVec<usize> = source.iter().copied().collect();
+        v.insert_many(index, inserted.iter().copied().filter(|_| true));
+        assert!(v.iter().eq(expected.iter()));
+      }
+    }
+  }
+
+  let mut v: SteadyVec<Box<usize>> = (0..10).map(Box::new).collect();
+  v.insert_many(4, (20..25).map(Box::new));
+  assert_eq!(v.len(), 15);
+  assert_eq!(*v[4], 20);
+  assert_eq!(*v[9], 4);
+}
+
+#[test]
+#[should_panic = "index is out of bounds"]
+fn insert_many_out_of_bounds() {
+  let mut v: SteadyVec<usize> = (0..10).collect();
+  v.insert_many(11, [1, 2]);
+}
+
+#[test]
+fn remove_range() {
+  for len in [0, 4, 9, 40, 100] {
+    for (start, end) in [(0, 0), (0, len), (len / 4, len / 2), (1, len)] {
+      if start > end || end > len {
+        continue;
+      }
+      let mut expected: Vec<usize> = (0..len).collect();
+      expected.drain(start..end);
+      let mut v: SteadyVec<usize> = (0..len).collect();
+      v.remove_range(start..end);
+      assert!(v.iter().eq(expected.iter()));
+    }
+  }
+
+  // removed elements are dropped exactly once
+  let tokens = Tokens::new();
+  let mut w: SteadyVec<Rc<()>> = SteadyVec::new();
+  w.resize(50, tokens.token());
+  w.remove_range(7..=40);
+  assert_eq!(w.len(), 16);
+  assert_eq!(tokens.alive(), 16);
+}
+
+#[test]
+fn get_many_mut() {
+  let mut v: SteadyVec<usize> = (0..100).collect();
+
+  let [a, b, c] = v.get_many_mut([2, 50, 99]).unwrap();
+  ::core::mem::swap(a, b);
+  *c += 1;
+  assert_eq!(v[2], 50);
+  assert_eq!(v[50], 2);
+  assert_eq!(v[99], 100);
+
+  assert_eq!(v.get_many_mut([]).map(|[]| ()), Ok(()));
+  assert_eq!(
+    v.get_many_mut([1, 100]).err(),
+    Some(GetManyMutError::IndexOutOfBounds)
+  );
+  assert_eq!(
+    v.get_many_mut([1, 7, 1]).err(),
+    Some(GetManyMutError::OverlappingIndices)
+  );
+}
+
+#[test]
+fn extract_if() {
+  let mut v: SteadyVec<usize> = (0..100).collect();
+  let extracted: Vec<usize> = v.extract_if(10..90, |e| *e % 3 == 0).collect();
+  assert!(extracted
+    .iter()
+    .copied()
+    .eq((10..90).filter(|e| e % 3 == 0)));
+  assert!(v.iter().copied().eq(
+    (0..10)
+      .chain((10..90).filter(|e| e % 3 != 0))
+      .chain(90..100)
+  ));
+
+  // dropped early, the remaining elements are retained
+  let mut v: SteadyVec<usize> = (0..100).collect();
+  let mut iter = v.extract_if(.., |e| *e % 2 == 1);
+  assert_eq!(iter.next(), Some(1));
+  assert_eq!(iter.next(), Some(3));
+  drop(iter);
+  assert!(v.iter().copied().eq([0, 2].into_iter().chain(4..100)));
+
+  // a panicking predicate doesn't drop anything twice
+  let tokens = Tokens::new();
+  let mut w: SteadyVec<Rc<()>> = SteadyVec::new();
+  w.resize(50, tokens.token());
+  let mut visited = 0;
+  assert!(panics(|| {
+    w.extract_if(.., |_| {
+      visited += 1;
+      if visited == 30 {
+        panic!("predicate panicked");
+      }
+      visited % 2 == 0
+    })
+    .for_each(drop);
+  }));
+  assert_eq!(w.len(), 50 - 14);
+  assert_eq!(tokens.alive(), 50 - 14);
+  drop(w);
+  assert_eq!(tokens.alive(), 0);
+}
+
+#[test]
+fn cursor() {
+  let v: SteadyVec<usize> = (0..40).collect();
+  let mut cursor = v.cursor(0);
+  for i in 0..40 {
+    assert_eq!(cursor.index(), i);
+    assert_eq!(cursor.current(), Some(&i));
+    cursor.move_next();
+  }
+  assert_eq!(cursor.current(), None);
+  cursor.move_next();
+  assert_eq!(cursor.index(), 40);
+  for i in (0..40).rev() {
+    cursor.move_prev();
+    assert_eq!(cursor.current(), Some(&i));
+  }
+  cursor.move_prev();
+  assert_eq!(cursor.index(), 0);
+
+  for index in [17, 3, 39, 4, 40, 0] {
+    cursor.seek(index);
+    assert_eq!(cursor.current(), v.get(index));
+  }
+
+  let mut v: SteadyVec<usize> = (0..40).collect();
+  let mut cursor = v.cursor_mut(10);
+  *cursor.current_mut().unwrap() += 100;
+  cursor.insert_before(1000);
+  assert_eq!(cursor.index(), 11);
+  assert_eq!(cursor.current(), Some(&110));
+  assert_eq!(cursor.remove_current(), Some(110));
+  assert_eq!(cursor.current(), Some(&11));
+  cursor.move_prev();
+  assert_eq!(cursor.current(), Some(&1000));
+  assert_eq!(cursor.as_cursor().current(), Some(&1000));
+
+  cursor.seek(5);
+  let front = cursor.split_before();
+  assert_eq!(cursor.index(), 0);
+  assert_eq!(cursor.current(), Some(&5));
+  assert!(front.iter().copied().eq(0..5));
+
+  cursor.seek(cursor.as_steady_vec().len());
+  assert_eq!(cursor.remove_current(), None);
+  cursor.insert_before(2000);
+  let expected: Vec<usize> = (5..10).chain([1000]).chain(11..40).collect();
+  assert!(v.iter().copied().eq(expected.into_iter().chain([2000])));
+}
+
+#[test]
+fn eq_debug() {
+  use ::std::{format, vec};
+
+  let v: SteadyVec<u8> = (0..40).collect();
+  let w: SteadyVec<u8> = (0..40).collect();
+  let vec: Vec<u8> = (0..40).collect();
+  let array: [u8; 40] = ::core::array::from_fn(|i| i as u8);
+
+  assert_eq!(v, w);
+  assert_eq!(v, vec);
+  assert_eq!(vec, v);
+  assert_eq!(v, vec[..]);
+  assert_eq!(vec[..], v);
+  assert_eq!(v, &vec[..]);
+  assert_eq!(&vec[..], v);
+  assert_eq!(v, array);
+  assert_eq!(array, v);
+
+  let mut changed = vec.clone();
+  changed[39] = 0;
+  assert_ne!(v, changed);
+  assert_ne!(v, vec[..39]);
+  assert_ne!(v, SteadyVec::<u8>::new());
+  assert_eq!(SteadyVec::<u8>::new(), [0u8; 0]);
+
+  // comparisons between different element types
+  let strings: SteadyVec<&str> = ["a", "b"].into_iter().collect();
+  let owned = vec![::std::string::String::from("a"), "b".into()];
+  assert_eq!(owned, strings);
+
+  let v: SteadyVec<usize> = (0..3).collect();
+  assert_eq!(format!("{v:?}"), "[0, 1, 2]");
+  assert_eq!(format!("{:?}", SteadyVec::<usize>::new()), "[]");
+}
+
+#[test]
+fn ord_hash() {
+  use ::std::{
+    collections::{BTreeMap, HashMap},
+    hash::{BuildHasher, RandomState},
+  };
+
+  let lists: [Vec<u8>; 7] = [
+    (0..0).collect(),
+    (0..5).collect(),
+    (0..40).collect(),
+    (0..41).collect(),
+    (1..10).collect(),
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 10].into(),
+    (0..41).map(|e| if e == 40 { 0 } else { e }).collect(),
+  ];
+  for a in &lists {
+    for b in &lists {
+      let steady_a: SteadyVec<u8> = a.iter().copied().collect();
+      let steady_b: SteadyVec<u8> = b.iter().copied().collect();
+      assert_eq!(steady_a.cmp(&steady_b), a.cmp(b));
+      assert_eq!(steady_a.partial_cmp(&steady_b), a.partial_cmp(b));
+    }
+  }
+
+  let floats: SteadyVec<f64> = [1.0, f64::NAN].into_iter().collect();
+  assert_eq!(floats.partial_cmp(&floats), None);
+
+  // hashes the same as the equivalent slice
+  let state = RandomState::new();
+  for list in &lists {
+    let steady: SteadyVec<u8> = list.iter().copied().collect();
+    assert_eq!(state.hash_one(&steady), state.hash_one(list));
+  }
+  let strings: Vec<&str> = ["a", "bc", "", "d"].repeat(5);
+  let steady: SteadyVec<&str> = strings.iter().copied().collect();
+  assert_eq!(state.hash_one(&steady), state.hash_one(&strings));
+
+  // usable as map keys
+  let mut btree_map = BTreeMap::new();
+  let mut hash_map = HashMap::new();
+  for (i, list) in lists.iter().enumerate() {
+    let steady: SteadyVec<u8> = list.iter().copied().collect();
+    btree_map.insert(steady.clone(), i);
+    hash_map.insert(steady, i);
+  }
+  for (i, list) in lists.iter().enumerate() {
+    let steady: SteadyVec<u8> = list.iter().copied().collect();
+    assert_eq!(btree_map.get(&steady), Some(&i));
+    assert_eq!(hash_map.get(&steady), Some(&i));
+  }
+}
+
+#[test]
+fn conversions() {
+  use ::std::{boxed::Box, vec};
+
+  let v: SteadyVec<usize> = SteadyVec::default();
+  assert!(v.is_empty());
+
+  let source: Vec<usize> = (0..100).collect();
+  let v = SteadyVec::from(source.clone());
+  assert_eq!(v, source);
+  assert_eq!(v.to_vec(), source);
+  let vec = v.into_vec();
+  assert_eq!(vec, source);
+  assert_eq!(vec.capacity(), 100);
+
+  assert_eq!(SteadyVec::from(&source[..]), source);
+  assert_eq!(SteadyVec::from(source.clone().into_boxed_slice()), source);
+  assert_eq!(SteadyVec::from([1, 2, 3]), [1, 2, 3]);
+  assert_eq!(Vec::from(SteadyVec::<usize>::new()), Vec::<usize>::new());
+
+  let boxed: Box<SteadyVec<usize>> = source.clone().into();
+  assert_eq!(*boxed, source);
+  assert_eq!(Vec::from(boxed), source);
+  let boxed: Box<SteadyVec<usize>> = Box::from(&source[..]);
+  assert_eq!(boxed.to_vec(), source);
+  let boxed: Box<SteadyVec<usize>> = [1, 2, 3].into();
+  assert_eq!(boxed.into_vec(), vec![1, 2, 3]);
+
+  // elements are moved, not dropped or duplicated
+  let tokens = Tokens::new();
+  let v = SteadyVec::from(vec![tokens.token(); 50]);
+  assert_eq!(tokens.alive(), 50);
+  let vec = v.into_vec();
+  assert_eq!(tokens.alive(), 50);
+  let v = SteadyVec::from([tokens.token(), tokens.token()]);
+  assert_eq!(tokens.alive(), 52);
+  drop((vec, v));
+  assert_eq!(tokens.alive(), 0);
+}
+
+#[test]
+fn send_sync() {
+  use {
+    super::{cursor::*, iter::*, slice::*},
+    ::std::{sync::Arc, thread},
+  };
+
+  // compile-time assertions, with the same bounds as `Vec` & its iterators
+  fn assert_send<T: Send>() {}
+  fn assert_sync<T: Sync>() {}
+  fn assert_send_sync<E: Send + Sync, F: Send + Sync>() {
+    assert_send::<SteadyVec<E>>();
+    assert_sync::<SteadyVec<E>>();
+    assert_send::<Box<SteadyVec<E>>>();
+    assert_send::<SteadyVecIter<E>>();
+    assert_sync::<SteadyVecIter<E>>();
+    assert_send::<SteadyVecIterMut<E>>();
+    assert_sync::<SteadyVecIterMut<E>>();
+    assert_send::<SteadyVecIntoIter<E>>();
+    assert_sync::<SteadyVecIntoIter<E>>();
+    assert_send::<BoxedSteadyVecIntoIter<E>>();
+    assert_send::<Segments<E>>();
+    assert_send::<SegmentsMut<E>>();
+    assert_sync::<SegmentsMut<E>>();
+    assert_send::<Drain<E>>();
+    assert_sync::<Drain<E>>();
+    assert_send::<ExtractIf<E, F>>();
+    assert_sync::<ExtractIf<E, F>>();
+    assert_send::<SteadySlice<E>>();
+    assert_send::<SteadySliceMut<E>>();
+    assert_sync::<SteadySliceMut<E>>();
+    assert_send::<Cursor<E>>();
+    assert_sync::<Cursor<E>>();
+    assert_send::<CursorMut<E>>();
+    assert_sync::<CursorMut<E>>();
+  }
+  assert_send_sync::<usize, fn(&mut usize) -> bool>();
+
+  // move into a worker thread
+  let v: SteadyVec<usize> = (0..1000).collect();
+  let v = thread::spawn(move || {
+    let mut v = v;
+    v.push(1000);
+    v
+  })
+  .join()
+  .unwrap();
+  assert!(v.iter().copied().eq(0..=1000));
+
+  // share through an `Arc`
+  let v = Arc::new(v);
+  let handles: Vec<_> = (0..4)
+    .map(|i| {
+      let v = Arc::clone(&v);
+      thread::spawn(move || v.iter().skip(i).step_by(4).sum::<usize>())
+    })
+    .collect();
+  let sum: usize = handles.into_iter().map(|h| h.join().unwrap()).sum();
+  assert_eq!(sum, (0..=1000).sum());
+
+  // mutate disjoint halves from scoped threads
+  let mut v: SteadyVec<usize> = (0..1000).collect();
+  let (left, right) = v.split_at_mut(500);
+  thread::scope(|scope| {
+    scope.spawn(move || left.into_iter().for_each(|e| *e *= 2));
+    scope.spawn(move || right.into_iter().for_each(|e| *e += 1));
+  });
+  assert!(v
+    .iter()
+    .copied()
+    .eq((0..500).map(|e| e * 2).chain(501..1001)));
+}
+
+/// A `SteadyVec` of a non-`Send` type can't be sent to another thread
+///
+/// ```compile_fail
+/// use {std::rc::Rc, steady_vec::SteadyVec};
+///
+/// let v: SteadyVec<Rc<()>> = SteadyVec::new();
+/// std::thread::spawn(move || drop(v));
+/// ```
+///
+/// Nor can a mutable iterator over a non-`Send` type
+///
+/// ```compile_fail
+/// use {std::rc::Rc, steady_vec::SteadyVec};
+///
+/// let mut v: SteadyVec<Rc<()>> = SteadyVec::new();
+/// let iter = v.iter_mut();
+/// std::thread::scope(|s| {
+///   s.spawn(move || drop(iter));
+/// });
+/// ```
+#[cfg(doctest)]
+fn _send_sync_compile_fail() {}
+
+#[test]
+fn extend_size_hint() {
+  // exact, inexact, and lying size hints
+  let v: SteadyVec<usize> = (0..1000).collect();
+  assert!(v.iter().copied().eq(0..1000));
+  let v: SteadyVec<usize> = (0..1000).filter(|e| e % 3 != 0).collect();
+  assert!(v.iter().copied().eq((0..1000).filter(|e| e % 3 != 0)));
+
+  struct Underestimate(::core::ops::Range<usize>);
+  impl Iterator for Underestimate {
+    type Item = usize;
+    fn next(&mut self) -> Option<usize> {
+      self.0.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+      (self.0.len() / 2, None)
+    }
+  }
+  let v: Box<SteadyVec<usize>> = Underestimate(0..300).collect();
+  assert!(v.iter().copied().eq(0..300));
+  assert!(v.capacity() < 1024);
+
+  // `Extend<&E>`
+  let mut v: SteadyVec<u8> = SteadyVec::new();
+  let source: Vec<u8> = (0..=255).collect();
+  v.extend(&source);
+  v.extend(source.iter().take(10));
+  assert_eq!(v.len(), 266);
+  assert!(v.iter().eq(source.iter().chain(&source[..10])));
+
+  // extending starting mid-subarray
+  let mut v: SteadyVec<usize> = (0..5).collect();
+  v.extend(5..100);
+  assert!(v.iter().copied().eq(0..100));
+
+  // a panicking iterator keeps the elements already written
+  let tokens = Tokens::new();
+  let mut w: SteadyVec<Rc<()>> = SteadyVec::new();
+  assert!(panics(|| {
+    w.extend((0..20).map(|i| {
+      if i == 13 {
+        panic!("iterator panicked");
+      }
+      tokens.token()
+    }))
+  }));
+  assert_eq!(w.len(), 13);
+  drop(w);
+  assert_eq!(tokens.alive(), 0);
+}
+
+#[test]
+fn write() {
+  use ::std::io::{IoSlice, Write as _};
+
+  let mut v: SteadyVec<u8> = SteadyVec::new();
+  assert_eq!(v.write(b"abc").unwrap(), 3);
+  let address = &v[0] as *const u8;
+
+  // spills across several segments
+  let long: Vec<u8> = (0..100).collect();
+  v.write_all(&long).unwrap();
+  assert_eq!(v.len(), 103);
+  assert!(v.iter().eq(b"abc".iter().chain(&long)));
+  assert_eq!(&v[0] as *const u8, address);
+
+  let bufs = [
+    IoSlice::new(b"hello"),
+    IoSlice::new(b""),
+    IoSlice::new(b", "),
+    IoSlice::new(&long),
+  ];
+  let mut w: SteadyVec<u8> = SteadyVec::new();
+  assert_eq!(w.write_vectored(&bufs).unwrap(), 107);
+  assert!(w.iter().eq(b"hello, ".iter().chain(&long)));
+  w.flush().unwrap();
+
+  let mut s: SteadyVec<u8> = SteadyVec::new();
+  ::std::fmt::Write::write_fmt(&mut s, format_args!("{}-{:?}", 12, "ab"))
+    .unwrap();
+  assert_eq!(s, *b"12-\"ab\"");
+  ::std::io::Write::write_fmt(&mut s, format_args!("{}", 3)).unwrap();
+  assert_eq!(s, *b"12-\"ab\"3");
+}
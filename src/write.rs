@@ -0,0 +1,71 @@
+//! Writing bytes into a `SteadyVec<u8>`
+
+use {
+  super::SteadyVec,
+  ::std::{fmt, io},
+};
+
+impl SteadyVec<u8> {
+  /// Copies the bytes onto the end, filling the current segment and spilling
+  /// into the following ones
+  ///
+  /// Capacity is reserved as needed. Reserving for several writes up front, as
+  /// `write_vectored` does, means each call only checks the capacity rather
+  /// than growing it.
+  #[inline]
+  fn write_bytes(&mut self, bytes: &[u8]) {
+    // safety: `bytes` is valid for reads of its length, and can't overlap the
+    // `SteadyVec` since `self` is borrowed mutably. `u8` is `Copy`, so the
+    // source bytes remain valid.
+    unsafe { self.append_from_ptr(bytes.as_ptr(), bytes.len()) };
+  }
+}
+
+impl io::Write for SteadyVec<u8> {
+  /// Appends the whole buffer, always returning `Ok(buf.len())`
+  ///
+  /// # Panics
+  ///
+  /// Panics if the new capacity would exceed [`SteadyVec::MAX_CAPACITY`].
+  #[inline]
+  fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+    self.write_bytes(buf);
+    Ok(buf.len())
+  }
+
+  /// Appends every buffer, reserving space for all of them at once
+  ///
+  /// # Panics
+  ///
+  /// Panics if the new capacity would exceed [`SteadyVec::MAX_CAPACITY`].
+  fn write_vectored(&mut self, bufs: &[io::IoSlice<'_>]) -> io::Result<usize> {
+    let len = bufs
+      .iter()
+      .fold(0usize, |len, buf| len.saturating_add(buf.len()));
+    self.reserve(len);
+    for buf in bufs {
+      self.write_bytes(buf);
+    }
+    Ok(len)
+  }
+
+  #[inline]
+  fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+    self.write_bytes(buf);
+    Ok(())
+  }
+
+  #[inline]
+  fn flush(&mut self) -> io::Result<()> {
+    Ok(())
+  }
+}
+
+impl fmt::Write for SteadyVec<u8> {
+  /// Appends the UTF-8 bytes of the string
+  #[inline]
+  fn write_str(&mut self, s: &str) -> fmt::Result {
+    self.write_bytes(s.as_bytes());
+    Ok(())
+  }
+}